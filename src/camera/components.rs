@@ -0,0 +1,152 @@
+use bevy::{camera::ScalingMode, prelude::*};
+
+use crate::settings;
+
+/// Trauma based shake, kicks toward impacts and a field of view that widens with ball speed,
+/// layered on top of wherever the camera is placed and undone again at the start of each frame.
+#[derive(Component)]
+pub struct CameraEffects {
+    pub trauma: f32,           // 0 to 1, the shake grows with its square
+    pub trauma_decay: f32,     // Per second
+    pub max_shake_offset: f32, // Units at full trauma
+    pub max_shake_angle: f32,  // Radians of roll at full trauma
+    pub shake_frequency: f32,
+    pub kick: Vec3,
+    pub max_kick: f32,
+    pub kick_recovery: f32, // Rate at which the kick eases back, per second
+    pub fov_boost: f32,     // Radians currently added to the field of view
+    pub fov_per_speed: f32, // Radians per unit of ball speed above its base speed
+    pub max_fov_boost: f32,
+    pub fov_response: f32, // Rate at which the boost follows the ball speed, per second
+    pub elapsed: f32,
+    pub applied_offset: Vec3,
+    pub applied_rotation: Quat,
+}
+
+impl Default for CameraEffects {
+    fn default() -> Self {
+        CameraEffects {
+            trauma: 0.0,
+            trauma_decay: 1.5,
+            max_shake_offset: 0.3,
+            max_shake_angle: 0.03,
+            shake_frequency: 15.0,
+            kick: Vec3::ZERO,
+            max_kick: 0.5,
+            kick_recovery: 10.0,
+            fov_boost: 0.0,
+            fov_per_speed: 0.01,
+            max_fov_boost: 0.15,
+            fov_response: 4.0,
+            elapsed: 0.0,
+            applied_offset: Vec3::ZERO,
+            applied_rotation: Quat::IDENTITY,
+        }
+    }
+}
+
+impl CameraEffects {
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).min(1.0);
+    }
+
+    pub fn add_kick(&mut self, kick: Vec3) {
+        self.kick = (self.kick + kick).clamp_length_max(self.max_kick);
+    }
+
+    /// Decays every effect by `delta` seconds and eases the field of view boost toward what
+    /// `speed_excess` calls for, all independent of how the time is split into frames.
+    pub fn advance(&mut self, delta: f32, speed_excess: f32) {
+        self.elapsed += delta;
+        self.trauma = (self.trauma - self.trauma_decay * delta).max(0.0);
+        self.kick *= (-self.kick_recovery * delta).exp();
+        let target = (speed_excess * self.fov_per_speed).clamp(0.0, self.max_fov_boost);
+        self.fov_boost = target + (self.fov_boost - target) * (-self.fov_response * delta).exp();
+    }
+
+    pub fn clear(&mut self) {
+        self.trauma = 0.0;
+        self.kick = Vec3::ZERO;
+        self.fov_boost = 0.0;
+    }
+
+    /// Shake offset and roll in camera space for the current trauma.
+    pub fn shake(&self) -> (Vec3, Quat) {
+        let shake = self.trauma * self.trauma;
+        let t = self.elapsed * self.shake_frequency;
+        let offset = Vec3::new(noise(t, 0.0), noise(t, 10.0), 0.0) * self.max_shake_offset;
+        let roll = noise(t, 20.0) * self.max_shake_angle;
+        (offset * shake, Quat::from_rotation_z(roll * shake))
+    }
+}
+
+// Smooth stand in for noise, sampled by time so it looks the same at any frame rate
+fn noise(t: f32, seed: f32) -> f32 {
+    0.6 * (t + seed).sin() + 0.4 * (2.3 * t + 1.7 * seed).sin()
+}
+
+/// Places the camera for the chosen mode, easing into each new pose so switching modes is smooth.
+/// The projection swaps at once, the top down view being orthographic.
+#[derive(Component)]
+pub struct CameraRig {
+    pub mode: settings::resources::CameraModeSetting,
+    pub playfield_half_size: Vec3,
+    pub response: f32, // Rate at which the camera eases toward its pose, per second
+}
+
+impl CameraRig {
+    pub fn new(mode: settings::resources::CameraModeSetting, playfield_half_size: Vec3) -> Self {
+        CameraRig {
+            mode,
+            playfield_half_size,
+            response: 5.0,
+        }
+    }
+
+    /// Where the camera should be in the current mode, given the paddle and ball positions.
+    pub fn pose(&self, paddle: Option<Vec3>, ball: Option<Vec3>) -> Transform {
+        let half_size = self.playfield_half_size;
+        let far_end = Vec3::new(0.0, 0.0, -half_size.z);
+        let behind = Vec3::new(0.0, 0.0, half_size.z + 9.0);
+        match self.mode {
+            settings::resources::CameraModeSetting::Fixed => {
+                Transform::from_translation(behind).looking_at(far_end, Vec3::Y)
+            }
+            settings::resources::CameraModeSetting::Lean => {
+                let lean = paddle.unwrap_or(Vec3::ZERO).truncate().extend(0.0);
+                Transform::from_translation(behind + lean * 0.4)
+                    .looking_at(far_end + lean * 0.1, Vec3::Y)
+            }
+            settings::resources::CameraModeSetting::Follow => {
+                let paddle = paddle.unwrap_or(behind);
+                let shoulder = paddle + Vec3::new(1.5, 1.5, 5.0);
+                let target = ball.unwrap_or(far_end).lerp(far_end, 0.3);
+                Transform::from_translation(shoulder).looking_at(target, Vec3::Y)
+            }
+            settings::resources::CameraModeSetting::TopDown => {
+                // Far end at the top of the screen, like looking at the other views from above
+                Transform::from_xyz(0.0, half_size.y + 30.0, 0.0).looking_at(Vec3::ZERO, -Vec3::Z)
+            }
+        }
+    }
+
+    pub fn projection(&self, fov: f32) -> Projection {
+        match self.mode {
+            settings::resources::CameraModeSetting::TopDown => {
+                Projection::Orthographic(OrthographicProjection {
+                    scaling_mode: ScalingMode::FixedVertical {
+                        viewport_height: self.playfield_half_size.z * 2.0 + 4.0,
+                    },
+                    far: 200.0,
+                    ..OrthographicProjection::default_3d()
+                })
+            }
+            _ => Projection::Perspective(PerspectiveProjection {
+                fov,
+                near: 0.1,
+                far: 200.0,
+                ..default()
+            }),
+        }
+    }
+}
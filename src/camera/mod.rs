@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+
+use crate::{gameplay, input, states};
+
+pub mod components;
+pub mod systems;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub enum CameraSet {
+    Restore,
+    Place,
+    Apply,
+}
+
+pub fn plugin(app: &mut App) {
+    app.configure_sets(
+        PostUpdate,
+        (CameraSet::Restore, CameraSet::Place, CameraSet::Apply)
+            .chain()
+            .before(TransformSystems::Propagate),
+    )
+    .add_systems(
+        PostUpdate,
+        (
+            systems::restore_camera.in_set(CameraSet::Restore),
+            (systems::sync_camera_mode, systems::place_camera)
+                .chain()
+                .in_set(CameraSet::Place),
+            (
+                systems::add_camera_trauma,
+                systems::kick_camera,
+                systems::apply_camera_effects,
+            )
+                .chain()
+                .in_set(CameraSet::Apply),
+        )
+            .run_if(in_state(states::PauseState::Running)),
+    )
+    .add_systems(
+        Update,
+        systems::orient_paddle_move_intent
+            .after(input::InputSet::Actions)
+            .before(gameplay::paddle::systems::paddle_control),
+    );
+}
@@ -0,0 +1,171 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{ball, brick, paddle, player};
+use crate::{camera, health, input, physics, settings};
+
+const PLAYER_DAMAGE_TRAUMA: f32 = 0.6;
+const BRICK_DEATH_TRAUMA: f32 = 0.25;
+const PADDLE_HIT_KICK: f32 = 0.15;
+// Screen axes leaning further than this into the view barely move anything the camera can see
+const MIN_VISIBLE_AXIS_LENGTH: f32 = 0.5;
+
+// Takes back last frame's effects, so anything placing the camera works from where it really is
+pub fn restore_camera(mut query: Query<(&mut Transform, &mut camera::components::CameraEffects)>) {
+    for (mut transform, mut effects) in &mut query {
+        transform.translation -= effects.applied_offset;
+        transform.rotation *= effects.applied_rotation.inverse();
+        effects.applied_offset = Vec3::ZERO;
+        effects.applied_rotation = Quat::IDENTITY;
+    }
+}
+
+pub fn sync_camera_mode(
+    settings: Res<settings::resources::Settings>,
+    mut query: Query<(&mut Projection, &mut camera::components::CameraRig)>,
+) {
+    for (mut projection, mut rig) in &mut query {
+        if rig.mode != settings.camera_mode {
+            rig.mode = settings.camera_mode;
+            *projection = rig.projection(settings.fov_degrees.to_radians());
+        }
+    }
+}
+
+pub fn place_camera(
+    time: Res<Time>,
+    paddle_query: Query<
+        &Transform,
+        (
+            With<paddle::components::Paddle>,
+            Without<camera::components::CameraRig>,
+        ),
+    >,
+    ball_query: Query<
+        &Transform,
+        (
+            With<ball::components::BallModifiers>,
+            Without<camera::components::CameraRig>,
+        ),
+    >,
+    mut camera_query: Query<(&mut Transform, &camera::components::CameraRig)>,
+) {
+    let paddle = paddle_query
+        .iter()
+        .next()
+        .map(|transform| transform.translation);
+    let ball = ball_query
+        .iter()
+        .next()
+        .map(|transform| transform.translation);
+    for (mut transform, rig) in &mut camera_query {
+        let pose = rig.pose(paddle, ball);
+        let t = 1.0 - (-rig.response * time.delta_secs()).exp();
+        transform.translation = transform.translation.lerp(pose.translation, t);
+        transform.rotation = transform.rotation.slerp(pose.rotation, t);
+    }
+}
+
+// Moves the paddle along the camera's screen axes, so pushing right moves it right on screen
+pub fn orient_paddle_move_intent(
+    mut intent: ResMut<input::resources::PaddleMoveIntent>,
+    camera_query: Query<&Transform, With<camera::components::CameraRig>>,
+) {
+    let Some(transform) = camera_query.iter().next() else {
+        return;
+    };
+    let right = transform.right().truncate().normalize_or(Vec2::X);
+    // Looking down on the playfield, screen up runs along the ball's path where the paddle can't
+    // follow, so vertical input is dropped rather than moving the paddle along the line of sight
+    let up = transform.up().truncate();
+    let up = if up.length() >= MIN_VISIBLE_AXIS_LENGTH {
+        up.normalize()
+    } else {
+        Vec2::ZERO
+    };
+    intent.0 = right * intent.0.x + up * intent.0.y;
+}
+
+pub fn add_camera_trauma(
+    settings: Res<settings::resources::Settings>,
+    mut heal_changed_messages: MessageReader<health::messages::HealChangedMessage>,
+    mut death_messages: MessageReader<health::messages::DeathMessage>,
+    player_query: Query<(), With<player::components::Player>>,
+    brick_query: Query<(), With<brick::components::Brick>>,
+    mut camera_query: Query<&mut camera::components::CameraEffects>,
+) {
+    if !settings.camera_effects {
+        return;
+    }
+    let mut trauma = 0.0;
+    for message in heal_changed_messages.read() {
+        if message.delta < 0 && player_query.contains(message.entity) {
+            trauma += PLAYER_DAMAGE_TRAUMA;
+        }
+    }
+    for message in death_messages.read() {
+        if brick_query.contains(message.entity) {
+            trauma += BRICK_DEATH_TRAUMA;
+        }
+    }
+    for mut effects in &mut camera_query {
+        effects.add_trauma(trauma);
+    }
+}
+
+pub fn kick_camera(
+    settings: Res<settings::resources::Settings>,
+    mut collision_messages: MessageReader<physics::messages::CollisionMessage>,
+    ball_query: Query<(), With<ball::components::BallModifiers>>,
+    paddle_query: Query<(), With<paddle::components::Paddle>>,
+    mut camera_query: Query<(&Transform, &mut camera::components::CameraEffects)>,
+) {
+    if !settings.camera_effects {
+        return;
+    }
+    for message in collision_messages.read() {
+        if !ball_query.contains(message.a) || !paddle_query.contains(message.b) {
+            continue;
+        }
+        for (transform, mut effects) in &mut camera_query {
+            let toward = (message.contact_point - transform.translation).normalize_or_zero();
+            effects.add_kick(toward * PADDLE_HIT_KICK);
+        }
+    }
+}
+
+pub fn apply_camera_effects(
+    time: Res<Time>,
+    settings: Res<settings::resources::Settings>,
+    ball_query: Query<(
+        &physics::components::Velocity,
+        &ball::components::BallModifiers,
+    )>,
+    mut camera_query: Query<(
+        &mut Transform,
+        &mut Projection,
+        &mut camera::components::CameraEffects,
+    )>,
+) {
+    let speed_excess = ball_query
+        .iter()
+        .map(|(velocity, modifiers)| velocity.0.length() - modifiers.base_velocity.length())
+        .fold(0.0, f32::max);
+    for (mut transform, mut projection, mut effects) in &mut camera_query {
+        if settings.camera_effects {
+            effects.advance(time.delta_secs(), speed_excess);
+        } else {
+            effects.clear();
+        }
+
+        let (shake_offset, shake_rotation) = effects.shake();
+        let offset = effects.kick + transform.rotation * shake_offset;
+        transform.translation += offset;
+        transform.rotation *= shake_rotation;
+        effects.applied_offset = offset;
+        effects.applied_rotation = shake_rotation;
+
+        if let Projection::Perspective(perspective) = projection.as_mut() {
+            perspective.fov = settings.fov_degrees.to_radians() + effects.fov_boost;
+        }
+    }
+}
@@ -0,0 +1,457 @@
+use bevy::prelude::*;
+use test_case::test_case;
+
+use crate::gameplay::{ball, brick, paddle, player};
+use crate::{camera, health, input, physics, settings};
+
+fn create_camera_app(camera_effects: bool) -> App {
+    let mut app = App::new();
+    app.init_resource::<Time>();
+    app.insert_resource(settings::resources::Settings {
+        camera_effects,
+        ..default()
+    });
+    app.add_message::<health::messages::HealChangedMessage>()
+        .add_message::<health::messages::DeathMessage>()
+        .add_message::<physics::messages::CollisionMessage>()
+        .add_systems(
+            Update,
+            (
+                camera::systems::restore_camera,
+                camera::systems::add_camera_trauma,
+                camera::systems::kick_camera,
+                camera::systems::apply_camera_effects,
+            )
+                .chain(),
+        );
+    app
+}
+
+fn spawn_camera(app: &mut App, transform: Transform) -> Entity {
+    app.world_mut()
+        .spawn((
+            transform,
+            Projection::Perspective(PerspectiveProjection::default()),
+            camera::components::CameraEffects::default(),
+        ))
+        .id()
+}
+
+fn advance(app: &mut App, seconds: f32) {
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(std::time::Duration::from_secs_f32(seconds));
+    app.update();
+}
+
+fn effects(app: &App, camera: Entity) -> &camera::components::CameraEffects {
+    app.world()
+        .get::<camera::components::CameraEffects>(camera)
+        .unwrap()
+}
+
+fn fov(app: &App, camera: Entity) -> f32 {
+    let Projection::Perspective(perspective) = app.world().get::<Projection>(camera).unwrap()
+    else {
+        panic!("camera should keep its perspective projection");
+    };
+    perspective.fov
+}
+
+#[derive(Clone, Copy)]
+enum Hit {
+    PlayerDamaged,
+    PlayerHealed,
+    BrickDied,
+    PlayerDied,
+}
+
+struct AddCameraTraumaCase {
+    hits: Vec<Hit>,
+    camera_effects: bool,
+    expected_trauma: f32,
+}
+
+#[test_case(
+    AddCameraTraumaCase {
+        hits: vec![Hit::PlayerDamaged],
+        camera_effects: true,
+        expected_trauma: 0.6,
+    }
+    ; "player damage shakes the camera"
+)]
+#[test_case(
+    AddCameraTraumaCase {
+        hits: vec![Hit::BrickDied],
+        camera_effects: true,
+        expected_trauma: 0.25,
+    }
+    ; "brick explosions shake the camera"
+)]
+#[test_case(
+    AddCameraTraumaCase {
+        hits: vec![Hit::PlayerHealed, Hit::PlayerDied],
+        camera_effects: true,
+        expected_trauma: 0.0,
+    }
+    ; "healing and other deaths leave it still"
+)]
+#[test_case(
+    AddCameraTraumaCase {
+        hits: vec![Hit::PlayerDamaged, Hit::BrickDied, Hit::PlayerDamaged],
+        camera_effects: true,
+        expected_trauma: 1.0,
+    }
+    ; "trauma stacks up to its cap"
+)]
+#[test_case(
+    AddCameraTraumaCase {
+        hits: vec![Hit::PlayerDamaged, Hit::BrickDied],
+        camera_effects: false,
+        expected_trauma: 0.0,
+    }
+    ; "turned off in the settings"
+)]
+fn test_add_camera_trauma(case: AddCameraTraumaCase) {
+    let mut app = create_camera_app(case.camera_effects);
+    let camera = spawn_camera(&mut app, Transform::default());
+    let player = app.world_mut().spawn(player::components::Player {}).id();
+    let brick = app.world_mut().spawn(brick::components::Brick).id();
+
+    for hit in case.hits {
+        match hit {
+            Hit::PlayerDamaged | Hit::PlayerHealed => {
+                let delta = if matches!(hit, Hit::PlayerDamaged) {
+                    -1
+                } else {
+                    1
+                };
+                app.world_mut()
+                    .write_message(health::messages::HealChangedMessage {
+                        entity: player,
+                        delta,
+                        source: None,
+                        kind: health::messages::DamageKind::Impact,
+                    });
+            }
+            Hit::BrickDied | Hit::PlayerDied => {
+                let entity = if matches!(hit, Hit::BrickDied) {
+                    brick
+                } else {
+                    player
+                };
+                app.world_mut()
+                    .write_message(health::messages::DeathMessage {
+                        entity,
+                        source: None,
+                        kind: health::messages::DamageKind::Impact,
+                    });
+            }
+        }
+    }
+    app.update();
+
+    assert!((effects(&app, camera).trauma - case.expected_trauma).abs() < 1e-6);
+}
+
+#[test]
+fn test_paddle_hit_kicks_toward_the_impact() {
+    let mut app = create_camera_app(true);
+    let camera = spawn_camera(&mut app, Transform::default());
+    let ball = app
+        .world_mut()
+        .spawn(ball::components::BallModifiers::starting())
+        .id();
+    let paddle = app.world_mut().spawn(paddle::components::Paddle).id();
+
+    app.world_mut()
+        .write_message(physics::messages::CollisionMessage {
+            a: ball,
+            b: paddle,
+            normal: Vec3::Z,
+            contact_point: Vec3::new(0.0, 0.0, -10.0),
+            penetration: 0.0,
+        });
+    app.update();
+
+    assert_eq!(effects(&app, camera).kick, Vec3::new(0.0, 0.0, -0.15));
+    assert_eq!(
+        app.world().get::<Transform>(camera).unwrap().translation,
+        Vec3::new(0.0, 0.0, -0.15)
+    );
+}
+
+#[test]
+fn test_effects_decay_independent_of_frame_rate() {
+    let start = || camera::components::CameraEffects {
+        trauma: 1.0,
+        kick: Vec3::X * 0.5,
+        ..default()
+    };
+    let mut one_step = start();
+    one_step.advance(0.2, 10.0);
+    let mut many_steps = start();
+    for _ in 0..20 {
+        many_steps.advance(0.01, 10.0);
+    }
+
+    assert!((one_step.trauma - many_steps.trauma).abs() < 1e-5);
+    assert!(one_step.kick.abs_diff_eq(many_steps.kick, 1e-5));
+    assert!((one_step.fov_boost - many_steps.fov_boost).abs() < 1e-5);
+    assert!(one_step.trauma < 1.0 && one_step.kick.x < 0.5);
+}
+
+#[test]
+fn test_camera_settles_back_where_it_was_placed() {
+    let mut app = create_camera_app(true);
+    let placed = Transform::from_xyz(0.0, 0.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y);
+    let camera = spawn_camera(&mut app, placed);
+    app.world_mut()
+        .get_mut::<camera::components::CameraEffects>(camera)
+        .unwrap()
+        .add_trauma(1.0);
+
+    advance(&mut app, 0.05);
+    let shaken = *app.world().get::<Transform>(camera).unwrap();
+    assert!(!shaken.translation.abs_diff_eq(placed.translation, 1e-3));
+
+    advance(&mut app, 1.0);
+    let settled = *app.world().get::<Transform>(camera).unwrap();
+    assert!(settled.translation.abs_diff_eq(placed.translation, 1e-5));
+    assert!(settled.rotation.abs_diff_eq(placed.rotation, 1e-5));
+}
+
+struct FovCase {
+    speed_over_base: f32,
+    camera_effects: bool,
+    expected_boost: f32,
+}
+
+#[test_case(
+    FovCase {
+        speed_over_base: 5.0,
+        camera_effects: true,
+        expected_boost: 0.05,
+    }
+    ; "widens with ball speed"
+)]
+#[test_case(
+    FovCase {
+        speed_over_base: 100.0,
+        camera_effects: true,
+        expected_boost: 0.15,
+    }
+    ; "stops widening at the cap"
+)]
+#[test_case(
+    FovCase {
+        speed_over_base: 100.0,
+        camera_effects: false,
+        expected_boost: 0.0,
+    }
+    ; "stays at the setting when turned off"
+)]
+fn test_fov_follows_ball_speed(case: FovCase) {
+    let mut app = create_camera_app(case.camera_effects);
+    let camera = spawn_camera(&mut app, Transform::default());
+    let modifiers = ball::components::BallModifiers::starting();
+    let speed = modifiers.base_velocity.length() + case.speed_over_base;
+    app.world_mut().spawn((
+        physics::components::Velocity(modifiers.base_velocity.normalize() * speed),
+        modifiers,
+    ));
+
+    for _ in 0..10 {
+        advance(&mut app, 1.0);
+    }
+
+    let base = settings::resources::Settings::default()
+        .fov_degrees
+        .to_radians();
+    assert!((fov(&app, camera) - (base + case.expected_boost)).abs() < 1e-4);
+}
+
+const HALF_SIZE: Vec3 = Vec3::new(10.0, 5.0, 20.0);
+
+fn rig(mode: settings::resources::CameraModeSetting) -> camera::components::CameraRig {
+    camera::components::CameraRig::new(mode, HALF_SIZE)
+}
+
+#[test]
+fn test_fixed_pose_is_the_view_from_behind_the_player() {
+    let pose = rig(settings::resources::CameraModeSetting::Fixed)
+        .pose(Some(Vec3::new(3.0, 2.0, 16.0)), Some(Vec3::ZERO));
+
+    assert_eq!(pose.translation, Vec3::new(0.0, 0.0, 29.0));
+    assert!(pose.forward().abs_diff_eq(Vec3::NEG_Z, 1e-6));
+}
+
+#[test]
+fn test_lean_pose_leans_with_the_paddle() {
+    let rig = rig(settings::resources::CameraModeSetting::Lean);
+
+    let centred = rig.pose(Some(Vec3::new(0.0, 0.0, 16.0)), None);
+    let leaning = rig.pose(Some(Vec3::new(4.0, -2.0, 16.0)), None);
+
+    assert_eq!(
+        leaning.translation - centred.translation,
+        Vec3::new(1.6, -0.8, 0.0)
+    );
+}
+
+#[test]
+fn test_follow_pose_looks_toward_the_ball_from_behind_the_paddle() {
+    let paddle = Vec3::new(0.0, 0.0, 16.0);
+    let pose = rig(settings::resources::CameraModeSetting::Follow)
+        .pose(Some(paddle), Some(Vec3::new(-5.0, 0.0, 0.0)));
+
+    assert!(pose.translation.z > paddle.z);
+    assert!(pose.forward().x < 0.0 && pose.forward().z < 0.0);
+}
+
+#[test]
+fn test_top_down_pose_keeps_the_far_end_at_the_top() {
+    let pose = rig(settings::resources::CameraModeSetting::TopDown).pose(None, None);
+
+    assert!(pose.forward().abs_diff_eq(Vec3::NEG_Y, 1e-6));
+    assert!(pose.up().abs_diff_eq(Vec3::NEG_Z, 1e-6));
+    assert!(pose.right().abs_diff_eq(Vec3::X, 1e-6));
+}
+
+fn create_rig_app(camera_mode: settings::resources::CameraModeSetting) -> App {
+    let mut app = App::new();
+    app.init_resource::<Time>();
+    app.insert_resource(settings::resources::Settings {
+        camera_mode,
+        ..default()
+    });
+    app.add_systems(
+        Update,
+        (
+            camera::systems::sync_camera_mode,
+            camera::systems::place_camera,
+        )
+            .chain(),
+    );
+    app
+}
+
+fn spawn_rig(app: &mut App) -> Entity {
+    let rig = rig(settings::resources::CameraModeSetting::Fixed);
+    app.world_mut()
+        .spawn((rig.projection(1.0), rig.pose(None, None), rig))
+        .id()
+}
+
+#[test]
+fn test_switching_modes_swaps_the_projection() {
+    let mut app = create_rig_app(settings::resources::CameraModeSetting::TopDown);
+    let camera = spawn_rig(&mut app);
+
+    app.update();
+    assert!(matches!(
+        app.world().get::<Projection>(camera).unwrap(),
+        Projection::Orthographic(_)
+    ));
+
+    app.world_mut()
+        .resource_mut::<settings::resources::Settings>()
+        .camera_mode = settings::resources::CameraModeSetting::Lean;
+    app.update();
+    assert!(matches!(
+        app.world().get::<Projection>(camera).unwrap(),
+        Projection::Perspective(_)
+    ));
+    assert_eq!(
+        app.world()
+            .get::<camera::components::CameraRig>(camera)
+            .unwrap()
+            .mode,
+        settings::resources::CameraModeSetting::Lean
+    );
+}
+
+#[test]
+fn test_switching_modes_eases_into_the_new_pose() {
+    let mut app = create_rig_app(settings::resources::CameraModeSetting::TopDown);
+    let camera = spawn_rig(&mut app);
+    let start = *app.world().get::<Transform>(camera).unwrap();
+    let target = rig(settings::resources::CameraModeSetting::TopDown).pose(None, None);
+
+    advance(&mut app, 0.05);
+    let easing = *app.world().get::<Transform>(camera).unwrap();
+    assert!(
+        easing.translation.distance(target.translation) > 0.1
+            && easing.translation.distance(start.translation) > 0.1
+    );
+
+    for _ in 0..10 {
+        advance(&mut app, 1.0);
+    }
+    let settled = *app.world().get::<Transform>(camera).unwrap();
+    assert!(settled.translation.abs_diff_eq(target.translation, 1e-3));
+    assert!(settled.rotation.angle_between(target.rotation) < 1e-3);
+}
+
+struct OrientPaddleMoveIntentCase {
+    camera: Transform,
+    intent: Vec2,
+    expected: Vec2,
+}
+
+#[test_case(
+    OrientPaddleMoveIntentCase {
+        camera: rig(settings::resources::CameraModeSetting::Fixed).pose(None, None),
+        intent: Vec2::new(1.0, 2.0),
+        expected: Vec2::new(1.0, 2.0),
+    }
+    ; "fixed is unchanged when looking down the playfield"
+)]
+#[test_case(
+    OrientPaddleMoveIntentCase {
+        camera: rig(settings::resources::CameraModeSetting::Lean)
+            .pose(Some(Vec3::new(3.0, 2.0, 16.0)), Some(Vec3::ZERO)),
+        intent: Vec2::new(1.0, 2.0),
+        expected: Vec2::new(1.0, 2.0),
+    }
+    ; "lean keeps both axes"
+)]
+#[test_case(
+    OrientPaddleMoveIntentCase {
+        camera: rig(settings::resources::CameraModeSetting::Follow)
+            .pose(Some(Vec3::new(3.0, 2.0, 16.0)), Some(Vec3::ZERO)),
+        intent: Vec2::new(1.0, 2.0),
+        expected: Vec2::new(1.0, 2.0),
+    }
+    ; "follow keeps both axes"
+)]
+#[test_case(
+    OrientPaddleMoveIntentCase {
+        camera: rig(settings::resources::CameraModeSetting::TopDown).pose(None, None),
+        intent: Vec2::new(1.0, 2.0),
+        expected: Vec2::new(1.0, 0.0),
+    }
+    ; "top down drops the vertical axis it looks along"
+)]
+#[test_case(
+    OrientPaddleMoveIntentCase {
+        camera: Transform::from_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2)),
+        intent: Vec2::new(1.0, 0.0),
+        expected: Vec2::new(0.0, 1.0),
+    }
+    ; "follows the screen axes of a rolled camera"
+)]
+fn test_orient_paddle_move_intent(case: OrientPaddleMoveIntentCase) {
+    let mut app = App::new();
+    app.insert_resource(input::resources::PaddleMoveIntent(case.intent));
+    app.add_systems(Update, camera::systems::orient_paddle_move_intent);
+    app.world_mut().spawn((
+        case.camera,
+        rig(settings::resources::CameraModeSetting::Fixed),
+    ));
+    app.update();
+
+    let intent = app.world().resource::<input::resources::PaddleMoveIntent>();
+    // The perspective views tilt their screen axes slightly off the paddle's plane
+    assert!(intent.0.abs_diff_eq(case.expected, 0.05));
+}
@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOverButtonAction {
+    Retry,
+    MainMenu,
+}
+
+pub type GameOverInteraction<'a> = (&'a Interaction, &'a GameOverButtonAction);
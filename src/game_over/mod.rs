@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+use crate::states;
+
+#[cfg(test)]
+mod tests;
+
+pub mod components;
+pub mod systems;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(
+        OnEnter(states::GameState::GameOver),
+        systems::game_over_setup,
+    )
+    .add_systems(
+        Update,
+        systems::game_over_action.run_if(in_state(states::GameState::GameOver)),
+    );
+}
@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+
+use crate::game_over::components;
+use crate::gameplay::level;
+use crate::main_menu::{self, systems::TEXT_COLOR};
+use crate::states;
+use crate::theme;
+
+pub fn game_over_setup(
+    mut commands: Commands,
+    run: Res<level::resources::Run>,
+    theme: Res<theme::resources::Theme>,
+) {
+    let stat_font = TextFont {
+        font_size: 28.0,
+        ..default()
+    };
+    let minutes = (run.stats.time_played / 60.0).floor() as u32;
+    let seconds = (run.stats.time_played % 60.0).floor() as u32;
+    let stats = [
+        format!("Score: {}", run.score),
+        format!("Bricks destroyed: {}", run.stats.bricks_destroyed),
+        format!("Longest rally: {}", run.stats.longest_rally),
+        format!("Max ball speed: {:.1}", run.stats.max_ball_speed),
+        format!("Time played: {minutes}:{seconds:02}"),
+    ];
+
+    commands.spawn((
+        Camera2d,
+        bevy_inspector_egui::bevy_egui::PrimaryEguiContext,
+        DespawnOnExit(states::GameState::GameOver),
+    ));
+    commands
+        .spawn((
+            DespawnOnExit(states::GameState::GameOver),
+            Node {
+                width: percent(100),
+                height: percent(100),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(theme.menu_background),
+                    theme::components::ThemedBackground,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Game Over"),
+                        TextFont {
+                            font_size: 67.0,
+                            ..default()
+                        },
+                        TextColor(TEXT_COLOR),
+                        Node {
+                            margin: UiRect::all(px(50)),
+                            ..default()
+                        },
+                    ));
+                    for stat in stats {
+                        parent.spawn((Text::new(stat), stat_font.clone(), TextColor(TEXT_COLOR)));
+                    }
+                    for (action, label) in [
+                        (components::GameOverButtonAction::Retry, "Retry"),
+                        (components::GameOverButtonAction::MainMenu, "Main Menu"),
+                    ] {
+                        parent.spawn((
+                            Button,
+                            main_menu::systems::button_node(),
+                            BackgroundColor(main_menu::systems::NORMAL_BUTTON),
+                            action,
+                            children![(
+                                Text::new(label),
+                                main_menu::systems::button_text_font(),
+                                TextColor(TEXT_COLOR),
+                            )],
+                        ));
+                    }
+                });
+        });
+}
+
+pub fn game_over_action(
+    interaction_query: Query<
+        components::GameOverInteraction,
+        main_menu::components::RecentButtonInteraction,
+    >,
+    checkpoint: Res<level::resources::LevelCheckpoint>,
+    mut run: ResMut<level::resources::Run>,
+    mut game_state: ResMut<NextState<states::GameState>>,
+) {
+    for (interaction, action) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match action {
+            components::GameOverButtonAction::Retry => {
+                *run = checkpoint.0.clone();
+                game_state.set(states::GameState::Gameplay);
+            }
+            components::GameOverButtonAction::MainMenu => {
+                game_state.set(states::GameState::Menu);
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+use test_case::test_case;
+
+use crate::game_over::{components, systems};
+use crate::gameplay::level;
+use crate::states;
+
+struct GameOverActionCase {
+    action: components::GameOverButtonAction,
+    interaction: Interaction,
+    expected_game_state: states::GameState,
+    expected_score: u32,
+}
+
+#[test_case(
+    GameOverActionCase {
+        action: components::GameOverButtonAction::Retry,
+        interaction: Interaction::Pressed,
+        expected_game_state: states::GameState::Gameplay,
+        expected_score: 100,
+    }
+; "retry restores the level checkpoint and restarts gameplay")]
+#[test_case(
+    GameOverActionCase {
+        action: components::GameOverButtonAction::MainMenu,
+        interaction: Interaction::Pressed,
+        expected_game_state: states::GameState::Menu,
+        expected_score: 400,
+    }
+; "main menu returns to the menu")]
+#[test_case(
+    GameOverActionCase {
+        action: components::GameOverButtonAction::Retry,
+        interaction: Interaction::Hovered,
+        expected_game_state: states::GameState::GameOver,
+        expected_score: 400,
+    }
+; "hovering does nothing")]
+fn test_game_over_action(case: GameOverActionCase) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        bevy::state::app::StatesPlugin,
+        states::plugin,
+    ));
+    app.insert_state(states::GameState::GameOver);
+    app.insert_resource(level::resources::LevelCheckpoint(level::resources::Run {
+        score: 100,
+        ..default()
+    }));
+    app.insert_resource(level::resources::Run {
+        score: 400,
+        ..default()
+    });
+    app.add_systems(Update, systems::game_over_action);
+    app.world_mut()
+        .spawn((Button, case.action, case.interaction));
+
+    // Update once for the processing of the system and once for the state change to
+    // take effect
+    app.update();
+    app.update();
+
+    let game_state = app.world().resource::<State<states::GameState>>();
+    assert_eq!(*game_state, case.expected_game_state);
+    let run = app.world().resource::<level::resources::Run>();
+    assert_eq!(run.score, case.expected_score);
+}
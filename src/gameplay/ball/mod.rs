@@ -1 +1,2 @@
 pub mod components;
+pub mod systems;
@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{ball, paddle};
+use crate::{physics, settings, theme};
+
+// How far ahead the aim assist looks
+const TRAJECTORY_PREVIEW_SECS: f32 = 3.0;
+// Steps between dots of the preview
+const TRAJECTORY_DOT_SPACING: usize = 4;
+
+pub fn draw_trajectory_preview(
+    mut gizmos: Gizmos,
+    settings: Res<settings::resources::Settings>,
+    theme: Res<theme::resources::Theme>,
+    fixed_time: Res<Time<Fixed>>,
+    ball_query: Query<
+        (
+            &Transform,
+            &physics::components::Velocity,
+            &physics::components::Curve,
+            &physics::components::BoundingSphere,
+        ),
+        With<ball::components::BallModifiers>,
+    >,
+    paddle_query: Query<&Transform, With<paddle::components::Paddle>>,
+    obstacle_query: Query<
+        (&Transform, &physics::components::BoundingCuboid),
+        Without<paddle::components::Paddle>,
+    >,
+) {
+    if !settings.trajectory_preview {
+        return;
+    }
+    let Some(paddle) = paddle_query.iter().next() else {
+        return;
+    };
+    let obstacles: Vec<physics::trajectory::Obstacle> = obstacle_query
+        .iter()
+        .map(|(transform, bounds)| physics::trajectory::Obstacle {
+            center: transform.translation,
+            half_extents: bounds.half_extents,
+        })
+        .collect();
+    let delta_secs = fixed_time.timestep().as_secs_f32();
+    let max_steps = (TRAJECTORY_PREVIEW_SECS / delta_secs) as usize;
+
+    for (transform, velocity, curve, sphere) in &ball_query {
+        let trajectory = physics::trajectory::predict_trajectory(
+            physics::trajectory::PredictedBall {
+                position: transform.translation,
+                velocity: velocity.0,
+                curve: curve.0,
+                radius: sphere.radius,
+            },
+            &obstacles,
+            paddle.translation.z,
+            delta_secs,
+            max_steps,
+        );
+
+        // Dots fade out the further ahead they are
+        for (index, point) in trajectory
+            .points
+            .iter()
+            .enumerate()
+            .step_by(TRAJECTORY_DOT_SPACING)
+        {
+            let fade = 1.0 - index as f32 / max_steps as f32;
+            gizmos.sphere(
+                Isometry3d::from_translation(*point),
+                sphere.radius * 0.25,
+                theme.trajectory.with_alpha(fade),
+            );
+        }
+        if let Some(crossing) = trajectory.plane_crossing {
+            gizmos.circle(
+                Isometry3d::from_translation(crossing),
+                sphere.radius,
+                theme.trajectory,
+            );
+        }
+    }
+}
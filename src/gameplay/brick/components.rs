@@ -1,4 +1,13 @@
 use bevy::prelude::*;
 
+use crate::health;
+
 #[derive(Component)]
 pub struct Brick;
+
+/// Bricks that still count towards clearing the level.
+pub type LivingBrick = (
+    With<Brick>,
+    With<health::components::Health>,
+    Without<health::components::Dying>,
+);
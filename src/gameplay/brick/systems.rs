@@ -1,7 +1,12 @@
-use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use bevy::{asset, prelude::*, render::render_resource};
+use rand::Rng;
 
-use crate::gameplay::{brick, playfield};
-use crate::{health, physics, states};
+use crate::gameplay::{brick, level, playfield};
+use crate::{health, particles, physics, states, theme};
+
+// Outer black border (slightly larger)
+const BORDER_PADDING: f32 = 0.25;
 
 pub fn spawn_brick_wall(
     mut commands: Commands,
@@ -10,9 +15,11 @@ pub fn spawn_brick_wall(
         &Transform,
         &physics::components::BoundingCuboid,
     )>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut stores: BrickAssetStores,
     playfield: Res<playfield::resources::Playfield>,
+    campaign: Res<level::resources::Campaign>,
+    theme: Res<theme::resources::Theme>,
+    mut over_time_messages: MessageWriter<health::messages::ApplyOverTimeMessage>,
 ) {
     let (_, enemy_goal_transform, enemy_goal_bounds) = goal_query
         .iter()
@@ -35,6 +42,8 @@ pub fn spawn_brick_wall(
     let total_width = bricks_x as f32 * brick_size.x;
     let total_height = bricks_y as f32 * brick_size.y;
 
+    let level = campaign.current_level();
+    let assets = BrickAssets::new(&mut stores, brick_size, &theme);
     let total_bricks = bricks_x * bricks_y;
     for index in 0..total_bricks {
         let x = index % bricks_x;
@@ -46,39 +55,85 @@ pub fn spawn_brick_wall(
             enemy_goal_transform.translation.z + wall_depth + brick_size.z,
         );
 
-        spawn_brick(&mut commands, &mut meshes, &mut materials, pos, brick_size);
+        spawn_brick(
+            &mut commands,
+            &mut over_time_messages,
+            &assets,
+            pos,
+            brick_size,
+            level,
+            &theme,
+        );
+    }
+}
+
+/// Asset collections the shared brick assets are added to.
+#[derive(SystemParam)]
+pub struct BrickAssetStores<'w> {
+    meshes: ResMut<'w, Assets<Mesh>>,
+    materials: ResMut<'w, Assets<StandardMaterial>>,
+    images: ResMut<'w, Assets<Image>>,
+}
+
+/// Shared by every brick of a wall, colors change by swapping to cached material variants.
+struct BrickAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+    border_mesh: Handle<Mesh>,
+    border_material: Handle<StandardMaterial>,
+    cracks: Handle<Image>,
+}
+
+impl BrickAssets {
+    fn new(stores: &mut BrickAssetStores, size: Vec3, theme: &theme::resources::Theme) -> Self {
+        BrickAssets {
+            mesh: stores.meshes.add(Cuboid::new(
+                size.x - BORDER_PADDING,
+                size.y - BORDER_PADDING,
+                size.z,
+            )),
+            material: stores.materials.add(StandardMaterial {
+                base_color: Color::from(
+                    theme
+                        .brick_health
+                        .last()
+                        .copied()
+                        .unwrap_or(LinearRgba::WHITE),
+                ),
+                ..default()
+            }),
+            border_mesh: stores.meshes.add(Cuboid::new(
+                size.x + BORDER_PADDING,
+                size.y + BORDER_PADDING,
+                size.z * 0.05, // thin
+            )),
+            border_material: stores.materials.add(StandardMaterial {
+                base_color: Color::BLACK,
+                unlit: true,
+                ..default()
+            }),
+            cracks: stores.images.add(crack_texture()),
+        }
     }
 }
 
 fn spawn_brick(
     commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
+    over_time_messages: &mut MessageWriter<health::messages::ApplyOverTimeMessage>,
+    assets: &BrickAssets,
     position: Vec3,
     size: Vec3,
+    level: &level::resources::Level,
+    theme: &theme::resources::Theme,
 ) {
-    // Outer black border (slightly larger)
-    let border_padding = 0.25;
-
     let border = commands
         .spawn((
             Name::new("Brick Border"),
-            Mesh3d(meshes.add(Cuboid::new(
-                size.x + border_padding,
-                size.y + border_padding,
-                size.z * 0.05, // thin
-            ))),
-            MeshMaterial3d(materials.add(StandardMaterial {
-                base_color: Color::BLACK,
-                unlit: true,
-                ..default()
-            })),
+            Mesh3d(assets.border_mesh.clone()),
+            MeshMaterial3d(assets.border_material.clone()),
         ))
         .id();
 
-    let healthy_color = LinearRgba::rgb(0.0, 1.0, 0.0);
-    let critical_color = LinearRgba::rgb(1.0, 0.0, 0.0);
-
     // Main colored brick
     let main = commands
         .spawn((
@@ -93,26 +148,128 @@ fn spawn_brick(
                 position.z,
             )),
             GlobalTransform::default(),
-            Mesh3d(meshes.add(Cuboid::new(
-                size.x - border_padding,
-                size.y - border_padding,
-                size.z,
-            ))),
-            MeshMaterial3d(materials.add(StandardMaterial {
-                base_color: Color::from(healthy_color),
-                ..default()
-            })),
-            health::components::Health { max: 3, current: 3 },
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            health::components::Health {
+                max: level.brick_health,
+                current: level.brick_health,
+            },
             health::components::HealthColors {
-                max: healthy_color,
-                min: critical_color,
+                stops: theme.brick_health.clone(),
             },
             health::components::ChangeOnCollision {
                 delta: -1,
+                kind: health::messages::DamageKind::Impact,
                 affected: health::components::Affects::SelfOnly,
             },
+            health::components::DeathEffect {
+                duration: 0.4,
+                fragments: 6,
+            },
             DespawnOnExit(states::GameState::Gameplay),
         ))
         .id();
+    commands.entity(main).insert((
+        particles::components::ImpactParticles(particles::messages::ParticleBurst {
+            count: 6,
+            speed: 6.0,
+            spread: 0.8,
+            lifetime: 0.3,
+            size: 0.15,
+            color: theme.brick_sparks,
+        }),
+        particles::components::DeathParticles(particles::messages::ParticleBurst {
+            count: 16,
+            speed: 10.0,
+            spread: 1.0,
+            lifetime: 0.5,
+            size: 0.2,
+            color: theme.brick_explosion,
+        }),
+    ));
+    if level.brick_shield > 0 {
+        commands.entity(main).insert((
+            health::components::Shield::new(level.brick_shield, 3.0, 0.5),
+            health::components::ShieldColor(theme.brick_shield),
+        ));
+    }
+    if level.brick_armor > 0 {
+        commands
+            .entity(main)
+            .insert(health::components::Armor(level.brick_armor));
+    }
+    if let Some(interval) = level.brick_regeneration {
+        over_time_messages.write(health::messages::ApplyOverTimeMessage {
+            entity: main,
+            kind: health::messages::OverTimeKind::Regeneration,
+            effect: health::components::OverTime::new(
+                1,
+                interval,
+                None,
+                health::components::Stacking::Refresh,
+            ),
+        });
+    }
+    if let Some(burn) = level.brick_burn {
+        commands
+            .entity(main)
+            .insert(health::components::OverTimeOnCollision {
+                kind: health::messages::OverTimeKind::Damage,
+                effect: burn,
+                affected: health::components::Affects::SelfOnly,
+            });
+    }
+    if level.brick_health > 1 {
+        commands
+            .entity(main)
+            .insert(health::components::DamageTiers::new(vec![
+                health::components::DamageTier {
+                    at: level.brick_health,
+                    mesh: assets.mesh.clone(),
+                    texture: None,
+                },
+                health::components::DamageTier {
+                    at: level.brick_health / 2,
+                    mesh: assets.mesh.clone(),
+                    texture: Some(assets.cracks.clone()),
+                },
+            ]));
+    }
     commands.entity(main).add_child(border);
 }
+
+// Dark jagged lines on white, multiplied over the health color of a damaged brick
+fn crack_texture() -> Image {
+    const SIZE: u32 = 64;
+    const CRACKS: usize = 4;
+    const CRACK_LENGTH: usize = 40;
+
+    let mut image = Image::new_fill(
+        render_resource::Extent3d {
+            width: SIZE,
+            height: SIZE,
+            depth_or_array_layers: 1,
+        },
+        render_resource::TextureDimension::D2,
+        &[255, 255, 255, 255],
+        render_resource::TextureFormat::Rgba8UnormSrgb,
+        asset::RenderAssetUsages::MAIN_WORLD | asset::RenderAssetUsages::RENDER_WORLD,
+    );
+    let mut rng = rand::rng();
+    for _ in 0..CRACKS {
+        let mut point = Vec2::new(
+            rng.random_range(0.0..SIZE as f32),
+            rng.random_range(0.0..SIZE as f32),
+        );
+        let mut heading = rng.random_range(0.0..std::f32::consts::TAU);
+        for _ in 0..CRACK_LENGTH {
+            heading += rng.random_range(-0.6..0.6);
+            point += Vec2::from_angle(heading);
+            if point.x < 0.0 || point.y < 0.0 || point.x >= SIZE as f32 || point.y >= SIZE as f32 {
+                break;
+            }
+            let _ = image.set_color_at(point.x as u32, point.y as u32, Color::srgb(0.2, 0.2, 0.2));
+        }
+    }
+    image
+}
@@ -1,7 +1,10 @@
 use bevy::prelude::*;
 
-use crate::gameplay::{brick, playfield};
+use crate::gameplay::{brick, level, playfield};
+use crate::health;
 use crate::physics;
+use crate::test_utils;
+use crate::theme;
 
 use test_case::test_case;
 
@@ -57,8 +60,12 @@ fn test_spawn_brick_wall(case: SpawnBrickWallCase) {
         brick_size: case.brick_size,
         ..default()
     });
+    app.insert_resource(level::resources::Campaign::standard());
     app.insert_resource(Assets::<Mesh>::default());
     app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(Assets::<Image>::default());
+    app.init_resource::<theme::resources::Theme>();
+    app.add_message::<health::messages::ApplyOverTimeMessage>();
 
     app.world_mut().spawn((
         playfield::components::Goal::Enemy,
@@ -86,3 +93,46 @@ fn test_spawn_brick_wall(case: SpawnBrickWallCase) {
         assert_eq!(transform.translation, *expected_brick_position);
     }
 }
+
+#[test_case(0, 0, false; "warm up bricks neither regenerate nor burn")]
+#[test_case(2, 1, true; "brick house bricks regenerate and burn when hit")]
+fn test_spawn_brick_wall_over_time_effects(
+    level: usize,
+    expected_regenerations: usize,
+    expected_burn: bool,
+) {
+    let mut app = App::new();
+    app.insert_resource(playfield::resources::Playfield {
+        brick_size: Vec3::ONE,
+        ..default()
+    });
+    app.insert_resource(level::resources::Campaign {
+        current: level,
+        ..level::resources::Campaign::standard()
+    });
+    app.insert_resource(Assets::<Mesh>::default());
+    app.insert_resource(Assets::<StandardMaterial>::default());
+    app.insert_resource(Assets::<Image>::default());
+    app.init_resource::<theme::resources::Theme>();
+    app.add_message::<health::messages::ApplyOverTimeMessage>();
+
+    app.world_mut().spawn((
+        playfield::components::Goal::Enemy,
+        Transform::default(),
+        physics::components::BoundingCuboid {
+            half_extents: Vec3::new(0.5, 0.5, 0.05),
+        },
+    ));
+    app.add_systems(Update, brick::systems::spawn_brick_wall);
+    app.update();
+
+    test_utils::assertions::assert_message_count::<health::messages::ApplyOverTimeMessage>(
+        &app,
+        expected_regenerations,
+    );
+    let mut burn_query = app
+        .world_mut()
+        .query_filtered::<&health::components::OverTimeOnCollision, With<brick::components::Brick>>(
+        );
+    assert_eq!(burn_query.iter(app.world()).next().is_some(), expected_burn);
+}
@@ -0,0 +1,5 @@
+pub mod resources;
+pub mod systems;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::health;
+
+pub const PROGRESS_FILE: &str = "progress.ron";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Level {
+    pub name: String,
+    pub brick_health: u8,
+    pub brick_shield: u8,
+    pub brick_armor: u8,
+    pub brick_regeneration: Option<f32>, // Seconds between each point a damaged brick heals
+    pub brick_burn: Option<health::components::OverTime>, // Damage over time a hit brick takes
+    pub points_per_brick: u32,
+}
+
+/// Ordered list of levels played one after another, `current` indexes into `levels`.
+#[derive(Resource, Clone, Debug)]
+pub struct Campaign {
+    pub levels: Vec<Level>,
+    pub current: usize,
+}
+
+impl Campaign {
+    pub fn standard() -> Self {
+        Campaign {
+            levels: vec![
+                Level {
+                    name: "Warm Up".into(),
+                    brick_health: 1,
+                    brick_shield: 0,
+                    brick_armor: 0,
+                    brick_regeneration: None,
+                    brick_burn: None,
+                    points_per_brick: 50,
+                },
+                Level {
+                    name: "Double Trouble".into(),
+                    brick_health: 2,
+                    brick_shield: 0,
+                    brick_armor: 0,
+                    brick_regeneration: None,
+                    brick_burn: None,
+                    points_per_brick: 100,
+                },
+                Level {
+                    name: "Brick House".into(),
+                    brick_health: 3,
+                    brick_shield: 1,
+                    brick_armor: 0,
+                    brick_regeneration: Some(4.0),
+                    brick_burn: Some(health::components::OverTime::new(
+                        1,
+                        1.0,
+                        Some(1.0),
+                        health::components::Stacking::Intensify { max_stacks: 2 },
+                    )),
+                    points_per_brick: 150,
+                },
+            ],
+            current: 0,
+        }
+    }
+
+    pub fn current_level(&self) -> &Level {
+        &self.levels[self.current]
+    }
+
+    pub fn is_last_level(&self) -> bool {
+        self.current + 1 >= self.levels.len()
+    }
+}
+
+/// Furthest level the player has reached, persisted between sessions.
+#[derive(Resource, Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+pub struct CampaignProgress {
+    pub unlocked: usize,
+}
+
+/// State carried from one level to the next within a single run.
+#[derive(Resource, Clone, Default, Debug, PartialEq)]
+pub struct Run {
+    pub score: u32,
+    pub player_health: Option<u8>,
+    pub stats: RunStats,
+}
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct RunStats {
+    pub bricks_destroyed: u32,
+    pub current_rally: u32,
+    pub longest_rally: u32,
+    pub max_ball_speed: f32,
+    pub time_played: f32,
+}
+
+/// Copy of the run as it was when the current level started, used to retry it.
+#[derive(Resource, Clone, Default)]
+pub struct LevelCheckpoint(pub Run);
+
+#[derive(Resource)]
+pub struct LevelCompleteTimer(pub Timer);
@@ -0,0 +1,178 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{ball, brick, level, paddle, player, playfield};
+use crate::{health, persistence, physics, states};
+
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const LEVEL_COMPLETE_SECS: f32 = 2.5;
+
+pub fn load_campaign_progress(mut commands: Commands) {
+    commands.insert_resource(persistence::load::<level::resources::CampaignProgress>(
+        level::resources::PROGRESS_FILE,
+    ));
+}
+
+pub fn save_campaign_progress(progress: Res<level::resources::CampaignProgress>) {
+    persistence::save(level::resources::PROGRESS_FILE, progress.as_ref());
+}
+
+pub fn award_brick_points(
+    mut death_messages: MessageReader<health::messages::DeathMessage>,
+    brick_query: Query<(), With<brick::components::Brick>>,
+    campaign: Res<level::resources::Campaign>,
+    mut run: ResMut<level::resources::Run>,
+) {
+    for message in death_messages.read() {
+        if brick_query.contains(message.entity) {
+            run.score += campaign.current_level().points_per_brick;
+            run.stats.bricks_destroyed += 1;
+        }
+    }
+}
+
+pub fn save_level_checkpoint(mut commands: Commands, run: Res<level::resources::Run>) {
+    commands.insert_resource(level::resources::LevelCheckpoint(run.clone()));
+}
+
+pub fn restart_level(
+    checkpoint: Res<level::resources::LevelCheckpoint>,
+    mut run: ResMut<level::resources::Run>,
+    mut game_state: ResMut<NextState<states::GameState>>,
+) {
+    *run = checkpoint.0.clone();
+    game_state.set(states::GameState::Gameplay);
+}
+
+pub fn track_rally(
+    mut collision_messages: MessageReader<physics::messages::CollisionMessage>,
+    paddle_query: Query<(), With<paddle::components::Paddle>>,
+    goal_query: Query<&playfield::components::Goal>,
+    mut run: ResMut<level::resources::Run>,
+) {
+    for message in collision_messages.read() {
+        if paddle_query.contains(message.b) {
+            run.stats.current_rally += 1;
+            run.stats.longest_rally = run.stats.longest_rally.max(run.stats.current_rally);
+        } else if goal_query.get(message.b) == Ok(&playfield::components::Goal::Player) {
+            run.stats.current_rally = 0;
+        }
+    }
+}
+
+pub fn track_max_ball_speed(
+    ball_query: Query<&physics::components::Velocity, With<ball::components::BallModifiers>>,
+    mut run: ResMut<level::resources::Run>,
+) {
+    for velocity in &ball_query {
+        let speed = velocity.0.length();
+        if speed > run.stats.max_ball_speed {
+            run.stats.max_ball_speed = speed;
+        }
+    }
+}
+
+pub fn track_time_played(time: Res<Time>, mut run: ResMut<level::resources::Run>) {
+    run.stats.time_played += time.delta_secs();
+}
+
+/// Runs after the player death check, a player that is dead or already going to game over
+/// does not complete the level by clearing the last brick.
+pub fn check_level_complete(
+    brick_query: Query<(), brick::components::LivingBrick>,
+    player_query: Query<&health::components::Health, With<player::components::Player>>,
+    campaign: Res<level::resources::Campaign>,
+    mut progress: ResMut<level::resources::CampaignProgress>,
+    mut run: ResMut<level::resources::Run>,
+    mut game_state: ResMut<NextState<states::GameState>>,
+) {
+    if !brick_query.is_empty()
+        || matches!(*game_state, NextState::Pending(_))
+        || player_query.iter().any(|health| health.current == 0)
+    {
+        return;
+    }
+
+    run.player_health = player_query.iter().next().map(|health| health.current);
+
+    let next_level = (campaign.current + 1).min(campaign.levels.len() - 1);
+    if next_level > progress.unlocked {
+        progress.unlocked = next_level;
+    }
+    game_state.set(states::GameState::LevelComplete);
+}
+
+pub fn level_complete_setup(
+    mut commands: Commands,
+    campaign: Res<level::resources::Campaign>,
+    run: Res<level::resources::Run>,
+) {
+    let title = if campaign.is_last_level() {
+        "Campaign Complete!".to_string()
+    } else {
+        format!("{} Complete!", campaign.current_level().name)
+    };
+
+    commands.insert_resource(level::resources::LevelCompleteTimer(Timer::from_seconds(
+        LEVEL_COMPLETE_SECS,
+        TimerMode::Once,
+    )));
+    commands.spawn((
+        Camera2d,
+        bevy_inspector_egui::bevy_egui::PrimaryEguiContext,
+        DespawnOnExit(states::GameState::LevelComplete),
+    ));
+    commands.spawn((
+        DespawnOnExit(states::GameState::LevelComplete),
+        Node {
+            width: percent(100),
+            height: percent(100),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        children![
+            (
+                Text::new(title),
+                TextFont {
+                    font_size: 67.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+            ),
+            (
+                Text::new(format!("Score: {}", run.score)),
+                TextFont {
+                    font_size: 33.0,
+                    ..default()
+                },
+                TextColor(TEXT_COLOR),
+                Node {
+                    margin: UiRect::all(px(20)),
+                    ..default()
+                },
+            ),
+        ],
+    ));
+}
+
+pub fn advance_level(
+    time: Res<Time>,
+    mut timer: ResMut<level::resources::LevelCompleteTimer>,
+    mut campaign: ResMut<level::resources::Campaign>,
+    mut run: ResMut<level::resources::Run>,
+    mut game_state: ResMut<NextState<states::GameState>>,
+) {
+    if !timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    if campaign.is_last_level() {
+        campaign.current = 0;
+        *run = level::resources::Run::default();
+        game_state.set(states::GameState::Menu);
+    } else {
+        campaign.current += 1;
+        game_state.set(states::GameState::Gameplay);
+    }
+}
@@ -0,0 +1,310 @@
+use bevy::prelude::*;
+use test_case::test_case;
+
+use crate::gameplay::{ball, brick, level, paddle, player, playfield};
+use crate::{health, physics, states};
+
+fn base_app(current_level: usize, unlocked: usize) -> App {
+    let mut app = App::new();
+    app.add_plugins((bevy::state::app::StatesPlugin, states::plugin));
+    app.insert_resource(level::resources::Campaign {
+        current: current_level,
+        ..level::resources::Campaign::standard()
+    });
+    app.insert_resource(level::resources::CampaignProgress { unlocked });
+    app.init_resource::<level::resources::Run>();
+    app
+}
+
+struct CheckLevelCompleteCase {
+    bricks: usize,
+    player_health: u8,
+    current_level: usize,
+    unlocked: usize,
+    expected_state: states::GameState,
+    expected_unlocked: usize,
+    expected_player_health: Option<u8>,
+}
+
+#[test_case(
+    CheckLevelCompleteCase {
+        bricks: 2,
+        player_health: 2,
+        current_level: 0,
+        unlocked: 0,
+        expected_state: states::GameState::Menu,
+        expected_unlocked: 0,
+        expected_player_health: None,
+    }
+    ; "bricks remaining keeps playing"
+)]
+#[test_case(
+    CheckLevelCompleteCase {
+        bricks: 0,
+        player_health: 2,
+        current_level: 0,
+        unlocked: 0,
+        expected_state: states::GameState::LevelComplete,
+        expected_unlocked: 1,
+        expected_player_health: Some(2),
+    }
+    ; "no bricks completes level and unlocks next"
+)]
+#[test_case(
+    CheckLevelCompleteCase {
+        bricks: 0,
+        player_health: 2,
+        current_level: 0,
+        unlocked: 2,
+        expected_state: states::GameState::LevelComplete,
+        expected_unlocked: 2,
+        expected_player_health: Some(2),
+    }
+    ; "replaying an earlier level keeps further progress"
+)]
+#[test_case(
+    CheckLevelCompleteCase {
+        bricks: 0,
+        player_health: 0,
+        current_level: 0,
+        unlocked: 0,
+        expected_state: states::GameState::Menu,
+        expected_unlocked: 0,
+        expected_player_health: None,
+    }
+    ; "dead player does not complete the level"
+)]
+fn test_check_level_complete(case: CheckLevelCompleteCase) {
+    let mut app = base_app(case.current_level, case.unlocked);
+    app.add_systems(Update, level::systems::check_level_complete);
+
+    app.world_mut().spawn((
+        player::components::Player {},
+        health::components::Health {
+            max: 3,
+            current: case.player_health,
+        },
+    ));
+    for _ in 0..case.bricks {
+        app.world_mut().spawn((
+            brick::components::Brick,
+            health::components::Health { max: 1, current: 1 },
+        ));
+    }
+
+    app.update();
+    app.update();
+
+    let game_state = app.world().resource::<State<states::GameState>>();
+    assert_eq!(*game_state, case.expected_state);
+    let progress = app.world().resource::<level::resources::CampaignProgress>();
+    assert_eq!(progress.unlocked, case.expected_unlocked);
+    let run = app.world().resource::<level::resources::Run>();
+    assert_eq!(run.player_health, case.expected_player_health);
+}
+
+#[test]
+fn test_player_death_takes_priority_over_level_complete() {
+    let mut app = base_app(0, 0);
+    app.add_message::<health::messages::DeathFinishedMessage>();
+    app.add_systems(
+        Update,
+        (
+            level::systems::check_level_complete.after(player::systems::restart_on_player_death),
+            player::systems::restart_on_player_death,
+        ),
+    );
+
+    // The player's death effect finished on the frame the last brick went away
+    let player = app
+        .world_mut()
+        .spawn((
+            player::components::Player {},
+            health::components::Health { max: 3, current: 1 },
+        ))
+        .id();
+    app.world_mut()
+        .write_message(health::messages::DeathFinishedMessage { entity: player });
+    app.update();
+
+    let next_state = app.world().resource::<NextState<states::GameState>>();
+    assert!(matches!(
+        next_state,
+        NextState::Pending(states::GameState::GameOver)
+    ));
+    let progress = app.world().resource::<level::resources::CampaignProgress>();
+    assert_eq!(progress.unlocked, 0);
+}
+
+#[test]
+fn test_award_brick_points_only_for_bricks() {
+    let mut app = base_app(1, 0);
+    app.add_message::<health::messages::DeathMessage>();
+    app.add_systems(Update, level::systems::award_brick_points);
+
+    let brick_entity = app.world_mut().spawn(brick::components::Brick).id();
+    let other_entity = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .write_message(health::messages::DeathMessage {
+            entity: brick_entity,
+            source: None,
+            kind: health::messages::DamageKind::Impact,
+        });
+    app.world_mut()
+        .write_message(health::messages::DeathMessage {
+            entity: other_entity,
+            source: None,
+            kind: health::messages::DamageKind::Impact,
+        });
+
+    app.update();
+
+    let run = app.world().resource::<level::resources::Run>();
+    let expected = level::resources::Campaign::standard().levels[1].points_per_brick;
+    assert_eq!(run.score, expected);
+}
+
+struct AdvanceLevelCase {
+    current_level: usize,
+    expected_level: usize,
+    expected_state: states::GameState,
+    expected_score: u32,
+}
+
+#[test_case(
+    AdvanceLevelCase {
+        current_level: 0,
+        expected_level: 1,
+        expected_state: states::GameState::Gameplay,
+        expected_score: 300,
+    }
+    ; "moves on to the next level keeping the score"
+)]
+#[test_case(
+    AdvanceLevelCase {
+        current_level: 2,
+        expected_level: 0,
+        expected_state: states::GameState::Menu,
+        expected_score: 0,
+    }
+    ; "finishing the last level returns to the menu"
+)]
+fn test_advance_level(case: AdvanceLevelCase) {
+    let mut app = base_app(case.current_level, 0);
+    app.insert_resource(level::resources::Run {
+        score: 300,
+        player_health: Some(2),
+        ..default()
+    });
+    app.insert_resource(level::resources::LevelCompleteTimer(Timer::from_seconds(
+        1.0,
+        TimerMode::Once,
+    )));
+    app.add_systems(Update, level::systems::advance_level);
+
+    let mut time: Time = Time::default();
+    time.advance_by(std::time::Duration::from_secs_f32(1.5));
+    app.insert_resource(time);
+
+    app.update();
+    app.update();
+
+    let campaign = app.world().resource::<level::resources::Campaign>();
+    assert_eq!(campaign.current, case.expected_level);
+    let game_state = app.world().resource::<State<states::GameState>>();
+    assert_eq!(*game_state, case.expected_state);
+    let run = app.world().resource::<level::resources::Run>();
+    assert_eq!(run.score, case.expected_score);
+}
+
+struct TrackRallyCase {
+    hits: Vec<Option<playfield::components::Goal>>,
+    expected_current: u32,
+    expected_longest: u32,
+}
+
+#[test_case(
+    TrackRallyCase {
+        hits: vec![None, None, None],
+        expected_current: 3,
+        expected_longest: 3,
+    }
+    ; "paddle hits extend the rally"
+)]
+#[test_case(
+    TrackRallyCase {
+        hits: vec![None, None, Some(playfield::components::Goal::Player), None],
+        expected_current: 1,
+        expected_longest: 2,
+    }
+    ; "losing the ball resets the current rally but keeps the longest"
+)]
+#[test_case(
+    TrackRallyCase {
+        hits: vec![None, Some(playfield::components::Goal::Enemy)],
+        expected_current: 1,
+        expected_longest: 1,
+    }
+    ; "enemy goal does not reset the rally"
+)]
+fn test_track_rally(case: TrackRallyCase) {
+    let mut app = base_app(0, 0);
+    app.add_message::<physics::messages::CollisionMessage>();
+    app.add_systems(Update, level::systems::track_rally);
+
+    let ball = app.world_mut().spawn_empty().id();
+    let paddle = app.world_mut().spawn(paddle::components::Paddle).id();
+    let player_goal = app
+        .world_mut()
+        .spawn(playfield::components::Goal::Player)
+        .id();
+    let enemy_goal = app
+        .world_mut()
+        .spawn(playfield::components::Goal::Enemy)
+        .id();
+
+    for hit in case.hits {
+        let b = match hit {
+            None => paddle,
+            Some(playfield::components::Goal::Player) => player_goal,
+            Some(playfield::components::Goal::Enemy) => enemy_goal,
+        };
+        app.world_mut()
+            .write_message(physics::messages::CollisionMessage {
+                a: ball,
+                b,
+                normal: Vec3::Z,
+                contact_point: Vec3::ZERO,
+                penetration: 0.0,
+            });
+    }
+
+    app.update();
+
+    let run = app.world().resource::<level::resources::Run>();
+    assert_eq!(run.stats.current_rally, case.expected_current);
+    assert_eq!(run.stats.longest_rally, case.expected_longest);
+}
+
+#[test]
+fn test_track_max_ball_speed_keeps_the_fastest() {
+    let mut app = base_app(0, 0);
+    app.add_systems(Update, level::systems::track_max_ball_speed);
+    let ball = app
+        .world_mut()
+        .spawn((
+            ball::components::BallModifiers::starting(),
+            physics::components::Velocity(Vec3::new(0.0, 3.0, 4.0)),
+        ))
+        .id();
+
+    app.update();
+    app.world_mut()
+        .get_mut::<physics::components::Velocity>(ball)
+        .unwrap()
+        .0 = Vec3::new(0.0, 0.0, 1.0);
+    app.update();
+
+    let run = app.world().resource::<level::resources::Run>();
+    assert_eq!(run.stats.max_ball_speed, 5.0);
+}
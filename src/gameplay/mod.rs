@@ -1,9 +1,10 @@
 use bevy::prelude::*;
 
-use crate::states;
+use crate::{health, states};
 
 pub mod ball;
 pub mod brick;
+pub mod level;
 pub mod paddle;
 pub mod player;
 pub mod playfield;
@@ -23,27 +24,65 @@ pub fn plugin(app: &mut App) {
     .add_systems(
         Update,
         (
-            paddle::systems::paddle_mouse_control,
+            paddle::systems::paddle_control.after(crate::input::InputSet::Actions),
             (
                 paddle::systems::initialize_paddle_motion,
                 paddle::systems::finalize_paddle_motion,
             )
                 .chain(),
         )
-            .run_if(in_state(states::GameState::Gameplay)),
+            .run_if(in_state(states::PauseState::Running)),
+    )
+    .add_systems(
+        Update,
+        (
+            player::systems::restart_on_player_death.after(health::systems::update_dying),
+            level::systems::award_brick_points.before(health::systems::handle_death),
+            level::systems::check_level_complete.after(player::systems::restart_on_player_death),
+            level::systems::track_max_ball_speed,
+            level::systems::track_time_played,
+        )
+            .run_if(in_state(states::PauseState::Running)),
     )
     .add_systems(
         Update,
-        player::systems::restart_on_player_death.run_if(in_state(states::GameState::Gameplay)),
+        ball::systems::draw_trajectory_preview.run_if(in_state(states::GameState::Gameplay)),
+    )
+    .add_systems(
+        Update,
+        level::systems::save_campaign_progress.run_if(
+            resource_changed::<level::resources::CampaignProgress>
+                .and(not(resource_added::<level::resources::CampaignProgress>)),
+        ),
+    )
+    .add_systems(
+        OnEnter(states::GameState::Gameplay),
+        level::systems::save_level_checkpoint,
+    )
+    .add_systems(
+        OnEnter(states::GameState::Restarting),
+        level::systems::restart_level,
+    )
+    .add_systems(
+        OnEnter(states::GameState::LevelComplete),
+        level::systems::level_complete_setup,
+    )
+    .add_systems(
+        Update,
+        level::systems::advance_level.run_if(in_state(states::GameState::LevelComplete)),
     )
     .add_systems(
         FixedUpdate,
         (
-            paddle::systems::apply_curve_from_motion_record
+            (
+                paddle::systems::sample_paddle_motion,
+                paddle::systems::apply_curve_from_motion_record,
+            )
                 .before(crate::physics::PhysicsSet::ApplyForces),
             (
                 paddle::systems::apply_paddle_impact_modifiers,
                 playfield::systems::handle_wall_collision,
+                level::systems::track_rally,
             )
                 .after(crate::physics::PhysicsSet::ResolveCollisions)
                 .run_if(in_state(states::GameState::Gameplay)),
@@ -52,7 +91,20 @@ pub fn plugin(app: &mut App) {
     .add_systems(
         PostUpdate,
         (playfield::systems::highlight_depth_lines,)
-            .before(crate::rendering::RenderingSet::Integrate)
+            .before(crate::rendering::RenderingSet::Tween)
             .run_if(in_state(states::GameState::Gameplay)),
-    );
+    )
+    .add_systems(
+        PostUpdate,
+        (
+            playfield::systems::spawn_depth_markers,
+            playfield::systems::update_depth_markers,
+        )
+            .chain()
+            .before(TransformSystems::Propagate)
+            .run_if(in_state(states::GameState::Gameplay)),
+    )
+    .insert_resource(level::resources::Campaign::standard())
+    .init_resource::<level::resources::Run>()
+    .add_systems(Startup, level::systems::load_campaign_progress);
 }
@@ -1,32 +1,112 @@
+use std::collections::VecDeque;
+
 use bevy::prelude::*;
 
+/// Number of recent paddle positions kept, ~0.25s at the default 64Hz fixed timestep.
+pub const PADDLE_MOTION_SAMPLES: usize = 16;
+
 #[derive(Component)]
 pub struct Paddle;
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PaddleMotionSample {
+    pub time: f32,
+    pub position: Vec2, // World units
+}
+
 #[derive(Component, Default)]
 pub struct PaddleMotionRecord {
-    pub start_pos: Vec2, // Position at collision
-    pub start_time: f32, // Time at collision
-    pub delta: Vec2,     // Computed delta over window
-    pub pending: bool,   // Is a curve calculation pending?
+    pub samples: VecDeque<PaddleMotionSample>, // Ring buffer of recent positions, oldest first
+    pub start_time: f32,                       // Time at collision
+    pub velocity: Vec2,                        // World units per second over the samples
+    pub pending: bool,                         // Is a curve calculation pending?
 }
 
-#[derive(Component, Default)]
+impl PaddleMotionRecord {
+    pub fn push_sample(&mut self, time: f32, position: Vec2) {
+        if self.samples.len() == PADDLE_MOTION_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples
+            .push_back(PaddleMotionSample { time, position });
+    }
+
+    /// Average velocity between the oldest and newest samples.
+    pub fn sampled_velocity(&self) -> Vec2 {
+        let (Some(oldest), Some(newest)) = (self.samples.front(), self.samples.back()) else {
+            return Vec2::ZERO;
+        };
+        let elapsed = newest.time - oldest.time;
+        if elapsed <= 0.0 {
+            return Vec2::ZERO;
+        }
+        (newest.position - oldest.position) / elapsed
+    }
+}
+
+/// Piecewise-linear mapping from paddle speed (world units per second) to curve strength.
+/// Points are sorted by speed, consecutive points at the same speed make a step.
+#[derive(Clone, Debug, Default, PartialEq, Reflect)]
+pub struct CurveResponse {
+    pub points: Vec<Vec2>,
+}
+
+impl CurveResponse {
+    /// The original feel, no curve below `normal_speed`, then two flat tiers.
+    pub fn stepped(
+        normal_speed: f32,
+        normal_scale: f32,
+        super_speed: f32,
+        super_scale: f32,
+    ) -> Self {
+        CurveResponse {
+            points: vec![
+                Vec2::ZERO,
+                Vec2::new(normal_speed, 0.0),
+                Vec2::new(normal_speed, normal_scale),
+                Vec2::new(super_speed, normal_scale),
+                Vec2::new(super_speed, super_scale),
+            ],
+        }
+    }
+
+    pub fn sample(&self, speed: f32) -> f32 {
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return 0.0;
+        };
+        if speed <= first.x {
+            return first.y;
+        }
+
+        for segment in self.points.windows(2) {
+            let (start, end) = (segment[0], segment[1]);
+            if speed < end.x {
+                let t = (speed - start.x) / (end.x - start.x);
+                return start.y.lerp(end.y, t);
+            }
+        }
+        last.y
+    }
+
+    /// Highest speed with a point, beyond it the response stays flat.
+    pub fn max_speed(&self) -> f32 {
+        self.points.last().map_or(0.0, |point| point.x)
+    }
+}
+
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
 pub struct PaddleImpactModifiers {
-    pub normal_curve_scale: f32,
-    pub super_curve_scale: f32,
-    pub normal_curve_position_delta_threshold: f32,
-    pub super_curve_position_delta_threshold: f32,
+    pub curve_response: CurveResponse,
+    pub hit_offset_deflection: f32, // Sideways speed added by a hit on the paddle's very edge
     pub z_speed_delta: f32,
 }
 
 impl PaddleImpactModifiers {
     pub fn starting() -> Self {
         PaddleImpactModifiers {
-            normal_curve_scale: 6.0,
-            super_curve_scale: 18.0,
-            normal_curve_position_delta_threshold: 0.002,
-            super_curve_position_delta_threshold: 0.006,
+            curve_response: CurveResponse::stepped(12.0, 6.0, 36.0, 18.0),
+            hit_offset_deflection: 6.0,
             z_speed_delta: 1.0,
         }
     }
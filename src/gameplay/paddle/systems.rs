@@ -1,10 +1,10 @@
 use bevy::prelude::*;
 
 use crate::gameplay::{ball, paddle, playfield};
-use crate::physics;
+use crate::{input, physics};
 
-pub fn paddle_mouse_control(
-    mut mouse_motion_message_reader: MessageReader<bevy::input::mouse::MouseMotion>,
+pub fn paddle_control(
+    intent: Res<input::resources::PaddleMoveIntent>,
     paddle_single: Single<
         (&mut Transform, &physics::components::BoundingCuboid),
         With<paddle::components::Paddle>,
@@ -14,13 +14,7 @@ pub fn paddle_mouse_control(
         &physics::components::BoundingCuboid,
     )>,
 ) {
-    let mut delta = Vec2::ZERO;
-
-    for ev in mouse_motion_message_reader.read() {
-        delta += ev.delta;
-    }
-
-    if delta == Vec2::ZERO {
+    if intent.0 == Vec2::ZERO {
         return;
     }
 
@@ -31,15 +25,13 @@ pub fn paddle_mouse_control(
         .find(|(goal, _)| **goal == playfield::components::Goal::Enemy);
 
     if let Some((_, bounds)) = enemy_goal {
-        let sensitivity = 0.025;
-        let new_velocity = delta * sensitivity;
         let x_abs_limit = bounds.half_extents.x - paddle_bounds.half_extents.x;
         let y_abs_limit = bounds.half_extents.y - paddle_bounds.half_extents.y;
 
         paddle_transform.translation.x =
-            (paddle_transform.translation.x + new_velocity.x).clamp(-x_abs_limit, x_abs_limit);
+            (paddle_transform.translation.x + intent.0.x).clamp(-x_abs_limit, x_abs_limit);
         paddle_transform.translation.y =
-            (paddle_transform.translation.y - new_velocity.y).clamp(-y_abs_limit, y_abs_limit); // invert Y if needed
+            (paddle_transform.translation.y + intent.0.y).clamp(-y_abs_limit, y_abs_limit);
     }
 }
 
@@ -49,37 +41,55 @@ pub fn apply_paddle_impact_modifiers(
         &mut physics::components::Velocity,
         With<physics::components::BoundingSphere>,
     >,
-    mut paddle_query: Query<
-        &paddle::components::PaddleImpactModifiers,
+    paddle_query: Query<
+        (
+            &Transform,
+            &physics::components::BoundingCuboid,
+            &paddle::components::PaddleImpactModifiers,
+        ),
         With<paddle::components::Paddle>,
     >,
 ) {
     for message in messages.read() {
-        if let (Ok(mut sphere_velocity), Ok(paddle_modifiers)) = (
-            sphere_query.get_mut(message.a),
-            paddle_query.get_mut(message.b),
-        ) {
+        if let (Ok(mut sphere_velocity), Ok((paddle_transform, paddle_bounds, paddle_modifiers))) =
+            (sphere_query.get_mut(message.a), paddle_query.get(message.b))
+        {
             let z_direction = sphere_velocity.0.z.signum();
             sphere_velocity.0.z += z_direction * paddle_modifiers.z_speed_delta;
+
+            // Hits away from the centre deflect outwards, -1 to 1 from edge to edge
+            let offset = ((message.contact_point - paddle_transform.translation).truncate()
+                / paddle_bounds.half_extents.truncate())
+            .clamp(Vec2::NEG_ONE, Vec2::ONE);
+            let deflection = offset * paddle_modifiers.hit_offset_deflection;
+            sphere_velocity.0.x += deflection.x;
+            sphere_velocity.0.y += deflection.y;
         }
     }
 }
 
+pub fn sample_paddle_motion(
+    time: Res<Time>,
+    paddle: Single<
+        (&Transform, &mut paddle::components::PaddleMotionRecord),
+        With<paddle::components::Paddle>,
+    >,
+) {
+    let (transform, mut record) = paddle.into_inner();
+    record.push_sample(time.elapsed_secs(), transform.translation.truncate());
+}
+
 pub fn initialize_paddle_motion(
     mut messages: MessageReader<physics::messages::CollisionMessage>,
     mut paddle_query: Query<
-        (&Transform, &mut paddle::components::PaddleMotionRecord),
-        (With<paddle::components::Paddle>,),
+        &mut paddle::components::PaddleMotionRecord,
+        With<paddle::components::Paddle>,
     >,
     time: Res<Time>,
 ) {
     for message in messages.read() {
-        if let Ok((paddle_transform, mut paddle_motion_record)) = paddle_query.get_mut(message.b) {
+        if let Ok(mut paddle_motion_record) = paddle_query.get_mut(message.b) {
             // Start motion record for curve computation
-            paddle_motion_record.start_pos = Vec2::new(
-                paddle_transform.translation.x,
-                paddle_transform.translation.y,
-            );
             paddle_motion_record.start_time = time.elapsed_secs();
             paddle_motion_record.pending = true;
         }
@@ -88,21 +98,15 @@ pub fn initialize_paddle_motion(
 
 pub fn finalize_paddle_motion(
     time: Res<Time>,
-    paddle_query: Single<
-        (&Transform, &mut paddle::components::PaddleMotionRecord),
+    mut record: Single<
+        &mut paddle::components::PaddleMotionRecord,
         With<paddle::components::Paddle>,
     >,
-    window_query: Single<&Window>,
 ) {
-    let window = window_query.into_inner();
-    let width = window.width();
-    let height = window.height();
-    let (transform, mut record) = paddle_query.into_inner();
-    // Only update if 200ms has elapsed from start of collision
+    // Only update if 200ms has elapsed from start of collision, by then the samples cover
+    // the paddle's motion since the hit
     if record.pending && time.elapsed_secs() - record.start_time >= 0.2 {
-        let current_pos = Vec2::new(transform.translation.x, transform.translation.y);
-        let raw_delta = current_pos - record.start_pos;
-        record.delta = Vec2::new(raw_delta.x / width, raw_delta.y / height);
+        record.velocity = record.sampled_velocity();
         record.pending = false; // Done computing, ready for curve
     }
 }
@@ -118,25 +122,73 @@ pub fn apply_curve_from_motion_record(
     >,
 ) {
     let (mut motion_record, modifiers) = paddle.into_inner();
-    if motion_record.pending || motion_record.delta == Vec2::ZERO {
+    if motion_record.pending || motion_record.velocity == Vec2::ZERO {
         return;
     }
 
-    // Compute curve based on motion delta over 30ms
-    ball_curve.0.x = match motion_record.delta.x {
-        d if d <= -modifiers.super_curve_position_delta_threshold => modifiers.super_curve_scale,
-        d if d <= -modifiers.normal_curve_position_delta_threshold => modifiers.normal_curve_scale,
-        d if d >= modifiers.super_curve_position_delta_threshold => -modifiers.super_curve_scale,
-        d if d >= modifiers.normal_curve_position_delta_threshold => -modifiers.normal_curve_scale,
-        _ => 0.0,
+    // Compute curve based on the paddle velocity after the hit, curving against the swipe
+    let response = &modifiers.curve_response;
+    let strength = |speed: f32| {
+        if speed == 0.0 {
+            0.0
+        } else {
+            -speed.signum() * response.sample(speed.abs())
+        }
     };
+    ball_curve.0.x = strength(motion_record.velocity.x);
+    ball_curve.0.y = strength(motion_record.velocity.y);
+    motion_record.velocity = Vec2::ZERO;
+}
 
-    ball_curve.0.y = match motion_record.delta.y {
-        d if d <= -modifiers.super_curve_position_delta_threshold => modifiers.super_curve_scale,
-        d if d <= -modifiers.normal_curve_position_delta_threshold => modifiers.normal_curve_scale,
-        d if d >= modifiers.super_curve_position_delta_threshold => -modifiers.super_curve_scale,
-        d if d >= modifiers.normal_curve_position_delta_threshold => -modifiers.normal_curve_scale,
-        _ => 0.0,
-    };
-    motion_record.delta = Vec2::ZERO;
+/// Debug window plotting each paddle's curve response, x is speed and y curve strength.
+pub fn preview_curve_response(
+    mut contexts: bevy_inspector_egui::bevy_egui::EguiContexts,
+    paddle_query: Query<(&Name, &paddle::components::PaddleImpactModifiers)>,
+) -> Result {
+    use bevy_inspector_egui::bevy_egui::egui;
+
+    let ctx = contexts.ctx_mut()?;
+    egui::Window::new("Curve Response")
+        .default_open(false)
+        .show(ctx, |ui| {
+            for (name, modifiers) in &paddle_query {
+                let response = &modifiers.curve_response;
+                let max_speed = response.max_speed().max(1.0) * 1.25;
+                let max_strength = response
+                    .points
+                    .iter()
+                    .map(|point| point.y.abs())
+                    .fold(1.0, f32::max);
+
+                ui.label(name.as_str());
+                let (rect, _) =
+                    ui.allocate_exact_size(egui::vec2(240.0, 120.0), egui::Sense::hover());
+                let painter = ui.painter_at(rect);
+                painter.rect_stroke(
+                    rect,
+                    0.0,
+                    egui::Stroke::new(1.0_f32, egui::Color32::GRAY),
+                    egui::StrokeKind::Inside,
+                );
+                let samples = 120;
+                let line = (0..=samples)
+                    .map(|i| {
+                        let speed = max_speed * i as f32 / samples as f32;
+                        let strength = response.sample(speed) / max_strength;
+                        egui::pos2(
+                            rect.left() + rect.width() * i as f32 / samples as f32,
+                            rect.bottom() - rect.height() * strength,
+                        )
+                    })
+                    .collect();
+                painter.add(egui::Shape::line(
+                    line,
+                    egui::Stroke::new(1.5_f32, egui::Color32::LIGHT_BLUE),
+                ));
+                ui.label(format!(
+                    "0 to {max_speed:.0} units/s, strength up to {max_strength:.1}"
+                ));
+            }
+        });
+    Ok(())
 }
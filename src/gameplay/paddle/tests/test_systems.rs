@@ -2,15 +2,14 @@ use bevy::prelude::*;
 use test_case::test_case;
 
 use crate::gameplay::{ball, paddle, playfield};
-use crate::physics;
+use crate::{input, physics};
 
-const SENSITIVITY: f32 = 0.025;
 const PLAYFIELD_HALF: f32 = 5.0;
 const PADDLE_HALF: f32 = 1.0;
 
 fn base_app() -> App {
     let mut app = App::new();
-    app.add_message::<bevy::input::mouse::MouseMotion>();
+    app.init_resource::<input::resources::PaddleMoveIntent>();
 
     app.world_mut().spawn((
         playfield::components::Goal::Enemy,
@@ -21,48 +20,48 @@ fn base_app() -> App {
     app
 }
 
-struct PaddleMouseControlCase {
+struct PaddleControlCase {
     starting_position: Vec3,
-    motion_deltas: Vec<Vec2>,
+    intent: Vec2,
     expected_position: Vec3,
 }
 
 #[test_case(
-    PaddleMouseControlCase {
+    PaddleControlCase {
         starting_position: Vec3::ZERO,
-        motion_deltas: vec![],
+        intent: Vec2::ZERO,
         expected_position: Vec3::ZERO,
     }
-    ; "no mouse movement"
+    ; "no movement"
 )]
 #[test_case(
-    PaddleMouseControlCase {
+    PaddleControlCase {
         starting_position: Vec3::ZERO,
-        motion_deltas: vec![Vec2::new(10.0, 0.0)],
-        expected_position: Vec3::new(10.0 * SENSITIVITY, 0.0, 0.0),
+        intent: Vec2::new(0.25, 0.0),
+        expected_position: Vec3::new(0.25, 0.0, 0.0),
     }
     ; "positive x movement"
 )]
 #[test_case(
-    PaddleMouseControlCase {
+    PaddleControlCase {
         starting_position: Vec3::ZERO,
-        motion_deltas: vec![Vec2::new(-10.0, 0.0)],
-        expected_position: Vec3::new(-10.0 * SENSITIVITY, 0.0, 0.0),
+        intent: Vec2::new(-0.25, 0.0),
+        expected_position: Vec3::new(-0.25, 0.0, 0.0),
     }
     ; "negative x movement"
 )]
 #[test_case(
-    PaddleMouseControlCase {
+    PaddleControlCase {
         starting_position: Vec3::ZERO,
-        motion_deltas: vec![Vec2::new(0.0, 10.0)],
-        expected_position: Vec3::new(0.0, -10.0 * SENSITIVITY, 0.0),
+        intent: Vec2::new(0.0, 0.25),
+        expected_position: Vec3::new(0.0, 0.25, 0.0),
     }
-    ; "positive y inverted"
+    ; "positive y movement"
 )]
 #[test_case(
-    PaddleMouseControlCase {
+    PaddleControlCase {
         starting_position: Vec3::ZERO,
-        motion_deltas: vec![Vec2::new(10_000.0, 10_000.0)],
+        intent: Vec2::new(250.0, -250.0),
         expected_position: Vec3::new(
             PLAYFIELD_HALF - PADDLE_HALF,
             -(PLAYFIELD_HALF - PADDLE_HALF),
@@ -71,9 +70,9 @@ struct PaddleMouseControlCase {
     }
     ; "clamps both axes"
 )]
-fn test_paddle_mouse_control(case: PaddleMouseControlCase) {
+fn test_paddle_control(case: PaddleControlCase) {
     let mut app = base_app();
-    app.add_systems(Update, paddle::systems::paddle_mouse_control);
+    app.add_systems(Update, paddle::systems::paddle_control);
 
     let paddle_entity = app
         .world_mut()
@@ -89,12 +88,7 @@ fn test_paddle_mouse_control(case: PaddleMouseControlCase) {
         ))
         .id();
 
-    let mut messages = app
-        .world_mut()
-        .resource_mut::<Messages<bevy::input::mouse::MouseMotion>>();
-    for delta in case.motion_deltas {
-        messages.write(bevy::input::mouse::MouseMotion { delta });
-    }
+    app.insert_resource(input::resources::PaddleMoveIntent(case.intent));
 
     app.update();
 
@@ -188,8 +182,13 @@ fn test_apply_paddle_impact_modifiers(case: ApplyPaddleImpactModifierCase) {
         .world_mut()
         .spawn((
             paddle::components::Paddle,
+            Transform::default(),
+            physics::components::BoundingCuboid {
+                half_extents: Vec3::new(2.0, 1.0, 0.1),
+            },
             paddle::components::PaddleImpactModifiers {
                 z_speed_delta: case.z_speed_delta,
+                hit_offset_deflection: 4.0,
                 ..default()
             },
         ))
@@ -233,19 +232,69 @@ fn test_apply_paddle_impact_modifiers(case: ApplyPaddleImpactModifierCase) {
     assert_eq!(velocity.0.z, case.expected_z_velocity);
 }
 
+#[test_case(Vec3::new(1.0, 0.0, 5.0), Vec2::ZERO; "centre hit goes straight back")]
+#[test_case(Vec3::new(2.0, 1.0, 5.0), Vec2::new(2.0, 4.0); "offset hit deflects in proportion")]
+#[test_case(Vec3::new(-1.0, -0.5, 5.0), Vec2::new(-4.0, -2.0); "edge hit deflects fully")]
+#[test_case(Vec3::new(-4.0, 3.0, 5.0), Vec2::new(-4.0, 4.0); "offset beyond the edge is capped")]
+fn test_apply_paddle_impact_modifiers_deflects_by_hit_offset(
+    contact_point: Vec3,
+    expected_lateral_velocity: Vec2,
+) {
+    let mut app = App::new();
+    app.add_message::<physics::messages::CollisionMessage>();
+    app.add_systems(Update, paddle::systems::apply_paddle_impact_modifiers);
+
+    let sphere_entity = app
+        .world_mut()
+        .spawn((
+            physics::components::Velocity(Vec3::new(0.0, 0.0, -20.0)),
+            physics::components::BoundingSphere::default(),
+        ))
+        .id();
+    let paddle_entity = app
+        .world_mut()
+        .spawn((
+            paddle::components::Paddle,
+            Transform::from_xyz(1.0, 0.0, 5.0),
+            physics::components::BoundingCuboid {
+                half_extents: Vec3::new(2.0, 1.0, 0.1),
+            },
+            paddle::components::PaddleImpactModifiers {
+                hit_offset_deflection: 4.0,
+                ..default()
+            },
+        ))
+        .id();
+    app.world_mut()
+        .write_message(physics::messages::CollisionMessage {
+            a: sphere_entity,
+            b: paddle_entity,
+            normal: Vec3::Z,
+            contact_point,
+            penetration: 0.0,
+        });
+
+    app.update();
+
+    let velocity = app
+        .world()
+        .get::<physics::components::Velocity>(sphere_entity)
+        .unwrap();
+    assert_eq!(velocity.0.truncate(), expected_lateral_velocity);
+    assert_eq!(velocity.0.z, -20.0);
+}
+
 struct InitializePaddleMotionCase {
-    start_pos: Vec2,
+    start_time: f32,
     pending: bool,
-    expected_start_pos: Vec2,
     expected_start_time: f32,
     expected_pending: bool,
 }
 
 #[test_case(
     InitializePaddleMotionCase {
-        start_pos: Vec2::ZERO,
+        start_time: 0.0,
         pending: false,
-        expected_start_pos: Vec2::ZERO,
         expected_start_time: 0.0,
         expected_pending: true,
     }
@@ -253,13 +302,12 @@ struct InitializePaddleMotionCase {
 )]
 #[test_case(
     InitializePaddleMotionCase {
-        start_pos: Vec2::new(1.0, 1.0),
-        pending: false,
-        expected_start_pos: Vec2::new(1.0, 1.0),
+        start_time: 3.0,
+        pending: true,
         expected_start_time: 0.0,
         expected_pending: true,
     }
-    ; "initializes motion record with non-zero position"
+    ; "restarts a pending motion record"
 )]
 fn test_initialize_paddle_motion(case: InitializePaddleMotionCase) {
     let mut app = App::new();
@@ -272,13 +320,8 @@ fn test_initialize_paddle_motion(case: InitializePaddleMotionCase) {
         .world_mut()
         .spawn((
             paddle::components::Paddle,
-            Transform {
-                translation: Vec3::new(case.start_pos.x, case.start_pos.y, 0.0),
-                ..default()
-            },
             paddle::components::PaddleMotionRecord {
-                start_pos: Vec2::ZERO,
-                start_time: 0.0,
+                start_time: case.start_time,
                 pending: case.pending,
                 ..default()
             },
@@ -304,90 +347,128 @@ fn test_initialize_paddle_motion(case: InitializePaddleMotionCase) {
         .get::<paddle::components::PaddleMotionRecord>(paddle_entity)
         .unwrap();
 
-    assert_eq!(record.start_pos, case.expected_start_pos);
     assert_eq!(record.start_time, case.expected_start_time);
     assert_eq!(record.pending, case.expected_pending);
 }
 
+#[test]
+fn test_sample_paddle_motion_keeps_most_recent_positions() {
+    let mut app = App::new();
+    app.add_systems(Update, paddle::systems::sample_paddle_motion);
+    app.insert_resource(Time::<()>::default());
+    let entity = app
+        .world_mut()
+        .spawn((
+            paddle::components::Paddle,
+            Transform::default(),
+            paddle::components::PaddleMotionRecord::default(),
+        ))
+        .id();
+
+    let step = 0.1;
+    let total_samples = paddle::components::PADDLE_MOTION_SAMPLES + 4;
+    for i in 0..total_samples {
+        app.world_mut()
+            .get_mut::<Transform>(entity)
+            .unwrap()
+            .translation = Vec3::new(i as f32, 0.0, 5.0);
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs_f32(step));
+        app.update();
+    }
+
+    let record = app
+        .world()
+        .get::<paddle::components::PaddleMotionRecord>(entity)
+        .unwrap();
+    assert_eq!(
+        record.samples.len(),
+        paddle::components::PADDLE_MOTION_SAMPLES
+    );
+    assert_eq!(
+        record.samples.back().unwrap().position,
+        Vec2::new((total_samples - 1) as f32, 0.0)
+    );
+    // One world unit per 0.1s regardless of window size
+    assert!((record.sampled_velocity() - Vec2::new(10.0, 0.0)).length() < 1e-3);
+}
+
 struct FinalizePaddleMotionCase {
-    start_pos: Vec2,
     start_time: f32,
     pending: bool,
-    current_pos: Vec3,
+    samples: Vec<(f32, Vec2)>,
     advance_time: f32,
-    expected_delta: Vec2,
+    expected_velocity: Vec2,
     expected_pending: bool,
 }
 
 #[test_case(
     FinalizePaddleMotionCase {
-        start_pos: Vec2::ZERO,
         start_time: 0.0,
         pending: true,
-        current_pos: Vec3::new(2.0, 1.0, 0.0),
+        samples: vec![(0.0, Vec2::ZERO), (0.1, Vec2::new(1.0, 0.5)), (0.25, Vec2::new(2.5, 1.0))],
         advance_time: 0.31,
-        expected_delta: Vec2::new(0.02, 0.02),
+        expected_velocity: Vec2::new(10.0, 4.0),
         expected_pending: false,
     }
-    ; "computes normalized delta after threshold"
+    ; "computes world space velocity after threshold"
 )]
 #[test_case(
     FinalizePaddleMotionCase {
-        start_pos: Vec2::ZERO,
         start_time: 0.0,
         pending: true,
-        current_pos: Vec3::new(2.0, 1.0, 0.0),
+        samples: vec![(0.0, Vec2::ZERO), (0.1, Vec2::new(2.0, 1.0))],
         advance_time: 0.1,
-        expected_delta: Vec2::ZERO,
+        expected_velocity: Vec2::ZERO,
         expected_pending: true,
     }
     ; "does nothing before threshold"
 )]
 #[test_case(
     FinalizePaddleMotionCase {
-        start_pos: Vec2::ZERO,
         start_time: 0.0,
         pending: false,
-        current_pos: Vec3::new(2.0, 1.0, 0.0),
+        samples: vec![(0.0, Vec2::ZERO), (0.1, Vec2::new(2.0, 1.0))],
+        advance_time: 0.31,
+        expected_velocity: Vec2::ZERO,
+        expected_pending: false,
+    }
+    ; "pending false does not update velocity"
+)]
+#[test_case(
+    FinalizePaddleMotionCase {
+        start_time: 0.0,
+        pending: true,
+        samples: vec![(0.2, Vec2::new(2.0, 1.0))],
         advance_time: 0.31,
-        expected_delta: Vec2::ZERO,
+        expected_velocity: Vec2::ZERO,
         expected_pending: false,
     }
-    ; "pending false does not update delta"
+    ; "single sample has no velocity"
 )]
 fn test_finalize_paddle_motion(case: FinalizePaddleMotionCase) {
     let mut app = App::new();
 
-    app.world_mut().spawn(Window {
-        resolution: (100, 50).into(),
-        ..default()
-    });
-
     app.add_systems(Update, paddle::systems::finalize_paddle_motion);
 
     let mut time: Time = Time::default();
+    time.advance_by(std::time::Duration::from_secs_f32(case.advance_time));
     app.insert_resource(time.clone());
 
+    let mut record = paddle::components::PaddleMotionRecord {
+        start_time: case.start_time,
+        pending: case.pending,
+        ..default()
+    };
+    for (sample_time, position) in case.samples {
+        record.push_sample(sample_time, position);
+    }
     let entity = app
         .world_mut()
-        .spawn((
-            paddle::components::Paddle,
-            Transform {
-                translation: case.current_pos,
-                ..default()
-            },
-            paddle::components::PaddleMotionRecord {
-                start_pos: case.start_pos,
-                start_time: case.start_time,
-                pending: case.pending,
-                delta: Vec2::ZERO,
-            },
-        ))
+        .spawn((paddle::components::Paddle, record))
         .id();
 
-    time.advance_by(std::time::Duration::from_secs_f32(case.advance_time));
-    *app.world_mut().resource_mut::<Time>() = time;
-
     app.update();
 
     let record = app
@@ -395,19 +476,24 @@ fn test_finalize_paddle_motion(case: FinalizePaddleMotionCase) {
         .get::<paddle::components::PaddleMotionRecord>(entity)
         .unwrap();
 
-    assert_eq!(record.delta, case.expected_delta);
+    assert!(
+        (record.velocity - case.expected_velocity).length() < 1e-4,
+        "expected {:?}, got {:?}",
+        case.expected_velocity,
+        record.velocity
+    );
     assert_eq!(record.pending, case.expected_pending);
 }
 
 struct ApplyCurveCase {
-    motion_delta: Vec2,
+    motion_velocity: Vec2,
     pending: bool,
     expected_curve: Vec2,
 }
 
 #[test_case(
     ApplyCurveCase {
-        motion_delta: Vec2::new(0.5, 0.5),
+        motion_velocity: Vec2::new(0.5, 0.5),
         pending: false,
         expected_curve: Vec2::ZERO,
     }
@@ -415,7 +501,7 @@ struct ApplyCurveCase {
 )]
 #[test_case(
     ApplyCurveCase {
-        motion_delta: Vec2::new(2.0, 0.0),
+        motion_velocity: Vec2::new(2.0, 0.0),
         pending: false,
         expected_curve: Vec2::new(-1.0, 0.0),
     }
@@ -423,7 +509,7 @@ struct ApplyCurveCase {
 )]
 #[test_case(
     ApplyCurveCase {
-        motion_delta: Vec2::new(5.0, 0.0),
+        motion_velocity: Vec2::new(5.0, 0.0),
         pending: false,
         expected_curve: Vec2::new(-3.0, 0.0),
     }
@@ -431,7 +517,7 @@ struct ApplyCurveCase {
 )]
 #[test_case(
     ApplyCurveCase {
-        motion_delta: Vec2::new(2.0, -2.0),
+        motion_velocity: Vec2::new(2.0, -2.0),
         pending: false,
         expected_curve: Vec2::new(-1.0, 1.0),
     }
@@ -439,7 +525,7 @@ struct ApplyCurveCase {
 )]
 #[test_case(
     ApplyCurveCase {
-        motion_delta: Vec2::new(5.0, 0.0),
+        motion_velocity: Vec2::new(5.0, 0.0),
         pending: true,
         expected_curve: Vec2::ZERO,
     }
@@ -447,15 +533,15 @@ struct ApplyCurveCase {
 )]
 #[test_case(
     ApplyCurveCase {
-        motion_delta: Vec2::ZERO,
+        motion_velocity: Vec2::ZERO,
         pending: false,
         expected_curve: Vec2::ZERO,
     }
-    ; "zero delta record does nothing"
+    ; "zero velocity record does nothing"
 )]
 #[test_case(
     ApplyCurveCase {
-        motion_delta: Vec2::new(-5.0, -5.0),
+        motion_velocity: Vec2::new(-5.0, -5.0),
         pending: false,
         expected_curve: Vec2::new(3.0, 3.0),
     }
@@ -463,7 +549,7 @@ struct ApplyCurveCase {
 )]
 #[test_case(
     ApplyCurveCase {
-        motion_delta: Vec2::new(-2.0, -2.0),
+        motion_velocity: Vec2::new(-2.0, -2.0),
         pending: false,
         expected_curve: Vec2::new(1.0, 1.0),
     }
@@ -471,7 +557,7 @@ struct ApplyCurveCase {
 )]
 #[test_case(
     ApplyCurveCase {
-        motion_delta: Vec2::new(2.0, 2.0),
+        motion_velocity: Vec2::new(2.0, 2.0),
         pending: false,
         expected_curve: Vec2::new(-1.0, -1.0),
     }
@@ -479,7 +565,7 @@ struct ApplyCurveCase {
 )]
 #[test_case(
     ApplyCurveCase {
-        motion_delta: Vec2::new(5.0, 5.0),
+        motion_velocity: Vec2::new(5.0, 5.0),
         pending: false,
         expected_curve: Vec2::new(-3.0, -3.0),
     }
@@ -500,14 +586,12 @@ fn test_apply_curve_from_motion_record(case: ApplyCurveCase) {
     app.world_mut().spawn((
         paddle::components::Paddle,
         paddle::components::PaddleImpactModifiers {
-            normal_curve_scale: 1.0,
-            super_curve_scale: 3.0,
-            normal_curve_position_delta_threshold: 1.0,
-            super_curve_position_delta_threshold: 4.0,
+            curve_response: paddle::components::CurveResponse::stepped(1.0, 1.0, 4.0, 3.0),
+            hit_offset_deflection: 0.0,
             z_speed_delta: 0.0,
         },
         paddle::components::PaddleMotionRecord {
-            delta: case.motion_delta,
+            velocity: case.motion_velocity,
             pending: case.pending,
             ..default()
         },
@@ -522,3 +606,25 @@ fn test_apply_curve_from_motion_record(case: ApplyCurveCase) {
 
     assert_eq!(curve.0, case.expected_curve);
 }
+
+fn ramp() -> paddle::components::CurveResponse {
+    paddle::components::CurveResponse {
+        points: vec![Vec2::ZERO, Vec2::new(2.0, 0.0), Vec2::new(6.0, 8.0)],
+    }
+}
+
+#[test_case(paddle::components::CurveResponse::stepped(1.0, 1.0, 4.0, 3.0), 0.5, 0.0; "stepped below normal speed")]
+#[test_case(paddle::components::CurveResponse::stepped(1.0, 1.0, 4.0, 3.0), 1.0, 1.0; "stepped at normal speed")]
+#[test_case(paddle::components::CurveResponse::stepped(1.0, 1.0, 4.0, 3.0), 3.9, 1.0; "stepped below super speed")]
+#[test_case(paddle::components::CurveResponse::stepped(1.0, 1.0, 4.0, 3.0), 10.0, 3.0; "stepped beyond last point")]
+#[test_case(ramp(), 1.0, 0.0; "ramp inside dead zone")]
+#[test_case(ramp(), 4.0, 4.0; "ramp interpolates")]
+#[test_case(ramp(), 9.0, 8.0; "ramp flat beyond max speed")]
+#[test_case(paddle::components::CurveResponse::default(), 5.0, 0.0; "empty response has no curve")]
+fn test_curve_response_sample(
+    response: paddle::components::CurveResponse,
+    speed: f32,
+    expected_strength: f32,
+) {
+    assert_eq!(response.sample(speed), expected_strength);
+}
@@ -1,2 +1,5 @@
 pub mod components;
 pub mod systems;
+
+#[cfg(test)]
+mod tests;
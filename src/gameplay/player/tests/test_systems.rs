@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use test_case::test_case;
+
+use crate::gameplay::player;
+use crate::{health, states};
+
+struct RestartOnPlayerDeathCase {
+    player: bool,
+    expected_state: states::GameState,
+}
+
+#[test_case(
+    RestartOnPlayerDeathCase {
+        player: true,
+        expected_state: states::GameState::GameOver,
+    }
+    ; "player finishing its death ends the game"
+)]
+#[test_case(
+    RestartOnPlayerDeathCase {
+        player: false,
+        expected_state: states::GameState::Menu,
+    }
+    ; "other entities finishing their death keep playing"
+)]
+fn test_restart_on_player_death(case: RestartOnPlayerDeathCase) {
+    let mut app = App::new();
+    app.add_plugins((bevy::state::app::StatesPlugin, states::plugin));
+    app.add_message::<health::messages::DeathFinishedMessage>()
+        .add_systems(Update, player::systems::restart_on_player_death);
+    let entity = if case.player {
+        app.world_mut().spawn(player::components::Player {}).id()
+    } else {
+        app.world_mut().spawn_empty().id()
+    };
+
+    app.world_mut()
+        .write_message(health::messages::DeathFinishedMessage { entity });
+    app.update();
+    app.update();
+
+    assert_eq!(
+        *app.world().resource::<State<states::GameState>>().get(),
+        case.expected_state
+    );
+}
@@ -1,10 +1,25 @@
 use bevy::prelude::*;
 
-#[derive(Component)]
-pub struct DepthLines;
+#[derive(Component, Default)]
+pub struct DepthLines {
+    pub highlighted: bool,
+}
 
 #[derive(Component, PartialEq, Eq, Copy, Clone)]
 pub enum Goal {
     Player,
     Enemy,
 }
+
+/// Wall that balls are projected onto, `normal` points into the playfield.
+#[derive(Component)]
+pub struct DepthMarkerWall {
+    pub normal: Vec3,
+}
+
+/// Projection of `ball` onto `wall`, so its depth can be read off the wall.
+#[derive(Component)]
+pub struct DepthMarker {
+    pub ball: Entity,
+    pub wall: Entity,
+}
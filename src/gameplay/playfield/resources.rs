@@ -4,5 +4,6 @@ use bevy::prelude::*;
 pub struct Playfield {
     pub wall_line_default_color: LinearRgba,
     pub wall_line_highlight_color: LinearRgba,
+    pub depth_marker_color: LinearRgba,
     pub brick_size: Vec3,
 }
@@ -3,35 +3,109 @@ use bevy::prelude::*;
 use crate::gameplay::{ball, playfield};
 use crate::physics;
 use crate::rendering;
+use crate::states;
+
+// How long a depth line takes to light up or fade back out
+const DEPTH_LINE_FADE_SECONDS: f32 = 0.2;
 
 pub fn highlight_depth_lines(
     ball_query: Single<(&Transform, &physics::components::BoundingSphere)>,
-    lines: Query<(Entity, &Transform), With<playfield::components::DepthLines>>,
+    mut lines: Query<(Entity, &Transform, &mut playfield::components::DepthLines)>,
     playfield: Res<playfield::resources::Playfield>,
-    mut messages: MessageWriter<rendering::messages::MaterialColorsChangedMessage>,
+    mut messages: MessageWriter<rendering::messages::TweenMessage>,
 ) {
     let (ball_transform, sphere) = ball_query.into_inner();
 
     let ball_z = ball_transform.translation.z;
-    // 2 ball diameters distance away, increase for smoothing animation, decrease
-    // to make animation more choppy
+    // Lines within 2 ball diameters light up
     let max_distance = 2.0 * sphere.radius * 2.0;
-    let base_color = &playfield.wall_line_default_color;
-    let highlight_color = &playfield.wall_line_highlight_color;
 
-    for (entity, line_transform) in lines {
+    for (entity, line_transform, mut depth_lines) in &mut lines {
         let distance = (line_transform.translation.z - ball_z).abs();
-        let t = (max_distance - distance).clamp(0.0, 1.0); // 0 if far, 1 if very closet);
-        let new_color = LinearRgba::mix(base_color, highlight_color, t);
+        let near = distance < max_distance;
+        if near == depth_lines.highlighted {
+            continue;
+        }
+        depth_lines.highlighted = near;
 
-        messages.write(rendering::messages::MaterialColorsChangedMessage {
+        let color = if near {
+            playfield.wall_line_highlight_color
+        } else {
+            playfield.wall_line_default_color
+        };
+        messages.write(rendering::messages::TweenMessage {
             entity,
-            emissive: Some(new_color),
-            base_color: None,
+            target: rendering::components::TweenTarget::Emissive(color),
+            duration: DEPTH_LINE_FADE_SECONDS,
+            easing: EaseFunction::SineInOut,
+            repeat: rendering::components::TweenRepeat::Once,
         });
     }
 }
 
+// Keeps markers from z-fighting with the wall they sit on
+const DEPTH_MARKER_OFFSET: f32 = 0.01;
+
+pub fn spawn_depth_markers(
+    mut commands: Commands,
+    balls: Query<
+        (Entity, &physics::components::BoundingSphere),
+        Added<ball::components::BallModifiers>,
+    >,
+    walls: Query<Entity, With<playfield::components::DepthMarkerWall>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    playfield: Res<playfield::resources::Playfield>,
+) {
+    for (ball, sphere) in &balls {
+        let mesh = meshes.add(Circle::new(sphere.radius));
+        let material = materials.add(StandardMaterial {
+            base_color: Color::from(playfield.depth_marker_color),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        });
+        for wall in &walls {
+            commands.spawn((
+                playfield::components::DepthMarker { ball, wall },
+                Name::new("Depth Marker"),
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::default(),
+                DespawnOnExit(states::GameState::Gameplay),
+            ));
+        }
+    }
+}
+
+pub fn update_depth_markers(
+    mut commands: Commands,
+    mut markers: Query<(Entity, &playfield::components::DepthMarker, &mut Transform)>,
+    balls: Query<&Transform, Without<playfield::components::DepthMarker>>,
+    walls: Query<(
+        &GlobalTransform,
+        &physics::components::BoundingCuboid,
+        &playfield::components::DepthMarkerWall,
+    )>,
+) {
+    for (entity, marker, mut transform) in &mut markers {
+        let (Ok(ball_transform), Ok((wall_transform, wall_bounds, wall))) =
+            (balls.get(marker.ball), walls.get(marker.wall))
+        else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+
+        // Flatten the ball onto the inner face of the wall, keeping the other two axes
+        let surface = wall_transform.translation()
+            + wall.normal * (wall_bounds.half_extents.dot(wall.normal.abs()) + DEPTH_MARKER_OFFSET);
+        let ball_position = ball_transform.translation;
+        transform.translation =
+            ball_position - wall.normal * (ball_position - surface).dot(wall.normal);
+        transform.rotation = Quat::from_rotation_arc(Vec3::Z, wall.normal);
+    }
+}
+
 pub fn handle_wall_collision(
     mut messages: MessageReader<physics::messages::CollisionMessage>,
     mut sphere_query: Query<
@@ -9,50 +9,80 @@ use crate::test_utils;
 #[derive(Debug)]
 struct HighlightDepthLinesCase {
     ball_z: f32,
-    lines_z: f32,
-    expected_mix: f32,
+    highlighted: bool,
+    expected_color: Option<LinearRgba>,
+    expected_highlighted: bool,
 }
 
 const PLAYFIELD_RES: playfield::resources::Playfield = playfield::resources::Playfield {
     wall_line_default_color: LinearRgba::new(0.0, 0.0, 0.0, 1.0),
     wall_line_highlight_color: LinearRgba::new(1.0, 0.0, 0.0, 1.0),
+    depth_marker_color: LinearRgba::new(0.0, 1.0, 0.0, 0.5),
     brick_size: Vec3::new(1.0, 1.0, 1.0),
 };
 
 #[test_case(
     HighlightDepthLinesCase {
         ball_z: 0.001,
-        lines_z: 0.0,
-        expected_mix: 1.0,
+        highlighted: false,
+        expected_color: Some(PLAYFIELD_RES.wall_line_highlight_color),
+        expected_highlighted: true,
     };
-    "emissive increases as ball move towards depth line"
+    "fades in as the ball reaches the depth line"
 )]
 #[test_case(
     HighlightDepthLinesCase {
         ball_z: -200.0,
-        lines_z: 0.0,
-        expected_mix: 0.0,
+        highlighted: true,
+        expected_color: Some(PLAYFIELD_RES.wall_line_default_color),
+        expected_highlighted: false,
     };
-    "emissive decreases as ball moves away from depth line"
+    "fades out as the ball moves away from the depth line"
 )]
-fn test_highlight_depth_lines_emits_color_change(case: HighlightDepthLinesCase) {
+#[test_case(
+    HighlightDepthLinesCase {
+        ball_z: 0.001,
+        highlighted: true,
+        expected_color: None,
+        expected_highlighted: true,
+    };
+    "no new tween while the ball stays near"
+)]
+#[test_case(
+    HighlightDepthLinesCase {
+        ball_z: -200.0,
+        highlighted: false,
+        expected_color: None,
+        expected_highlighted: false,
+    };
+    "no new tween while the ball stays away"
+)]
+fn test_highlight_depth_lines_tweens_on_change(case: HighlightDepthLinesCase) {
     let mut app = App::new();
-    let entity = run_highlight_depth_lines(&mut app, case.ball_z, case.lines_z);
-    let expected_color = LinearRgba::mix(
-        &PLAYFIELD_RES.wall_line_default_color,
-        &PLAYFIELD_RES.wall_line_highlight_color,
-        case.expected_mix,
-    );
-    let expected_messages = vec![rendering::messages::MaterialColorsChangedMessage {
-        entity,
-        base_color: None,
-        emissive: Some(expected_color),
-    }];
+    let entity = run_highlight_depth_lines(&mut app, case.ball_z, case.highlighted);
 
+    let expected_messages: Vec<rendering::messages::TweenMessage> = case
+        .expected_color
+        .map(|color| rendering::messages::TweenMessage {
+            entity,
+            target: rendering::components::TweenTarget::Emissive(color),
+            duration: 0.2,
+            easing: EaseFunction::SineInOut,
+            repeat: rendering::components::TweenRepeat::Once,
+        })
+        .into_iter()
+        .collect();
     test_utils::assertions::assert_messages(&app, &expected_messages);
+    assert_eq!(
+        app.world()
+            .get::<playfield::components::DepthLines>(entity)
+            .unwrap()
+            .highlighted,
+        case.expected_highlighted
+    );
 }
 
-fn run_highlight_depth_lines(app: &mut App, ball_z: f32, lines_z: f32) -> Entity {
+fn run_highlight_depth_lines(app: &mut App, ball_z: f32, highlighted: bool) -> Entity {
     app.insert_resource(PLAYFIELD_RES);
 
     let ball_modifiers = ball::components::BallModifiers::starting();
@@ -67,12 +97,12 @@ fn run_highlight_depth_lines(app: &mut App, ball_z: f32, lines_z: f32) -> Entity
     let lines_entity = app
         .world_mut()
         .spawn((
-            playfield::components::DepthLines,
-            Transform::from_translation(Vec3::Z * lines_z),
+            playfield::components::DepthLines { highlighted },
+            Transform::default(),
         ))
         .id();
 
-    app.add_message::<rendering::messages::MaterialColorsChangedMessage>();
+    app.add_message::<rendering::messages::TweenMessage>();
     app.add_systems(Update, playfield::systems::highlight_depth_lines);
 
     app.update();
@@ -80,7 +110,177 @@ fn run_highlight_depth_lines(app: &mut App, ball_z: f32, lines_z: f32) -> Entity
     lines_entity
 }
 
-use std::f32::EPSILON;
+fn create_depth_marker_app() -> App {
+    let mut app = App::new();
+    app.insert_resource(PLAYFIELD_RES);
+    app.init_resource::<Assets<Mesh>>();
+    app.init_resource::<Assets<StandardMaterial>>();
+    app.add_systems(
+        Update,
+        (
+            playfield::systems::spawn_depth_markers,
+            playfield::systems::update_depth_markers,
+        )
+            .chain(),
+    );
+    app
+}
+
+fn spawn_ball(app: &mut App, position: Vec3) -> Entity {
+    let ball_modifiers = ball::components::BallModifiers::starting();
+    app.world_mut()
+        .spawn((
+            physics::components::BoundingSphere {
+                radius: ball_modifiers.base_radius,
+            },
+            ball_modifiers,
+            Transform::from_translation(position),
+        ))
+        .id()
+}
+
+fn spawn_marker_wall(app: &mut App, translation: Vec3, half_extents: Vec3) -> Entity {
+    app.world_mut()
+        .spawn((
+            playfield::components::DepthMarkerWall {
+                normal: -translation.normalize(),
+            },
+            physics::components::BoundingCuboid { half_extents },
+            GlobalTransform::from_translation(translation),
+        ))
+        .id()
+}
+
+fn markers_of(app: &mut App, ball: Entity) -> Vec<(Entity, Transform)> {
+    app.world_mut()
+        .query::<(&playfield::components::DepthMarker, &Transform)>()
+        .iter(app.world())
+        .filter(|(marker, _)| marker.ball == ball)
+        .map(|(marker, transform)| (marker.wall, *transform))
+        .collect()
+}
+
+struct UpdateDepthMarkersCase {
+    wall_translation: Vec3,
+    wall_half_extents: Vec3,
+    expected_translation: Vec3,
+    expected_facing: Vec3,
+}
+
+#[test_case(
+    UpdateDepthMarkersCase {
+        wall_translation: Vec3::new(-10.0, 0.0, 0.0),
+        wall_half_extents: Vec3::new(0.05, 5.0, 20.0),
+        expected_translation: Vec3::new(-9.94, 2.0, -7.0),
+        expected_facing: Vec3::X,
+    }; "left wall keeps height and depth"
+)]
+#[test_case(
+    UpdateDepthMarkersCase {
+        wall_translation: Vec3::new(10.0, 0.0, 0.0),
+        wall_half_extents: Vec3::new(0.05, 5.0, 20.0),
+        expected_translation: Vec3::new(9.94, 2.0, -7.0),
+        expected_facing: Vec3::NEG_X,
+    }; "right wall keeps height and depth"
+)]
+#[test_case(
+    UpdateDepthMarkersCase {
+        wall_translation: Vec3::new(0.0, -5.0, 0.0),
+        wall_half_extents: Vec3::new(10.0, 0.05, 20.0),
+        expected_translation: Vec3::new(1.0, -4.94, -7.0),
+        expected_facing: Vec3::Y,
+    }; "floor keeps x and depth"
+)]
+#[test_case(
+    UpdateDepthMarkersCase {
+        wall_translation: Vec3::new(0.0, 5.0, 0.0),
+        wall_half_extents: Vec3::new(10.0, 0.05, 20.0),
+        expected_translation: Vec3::new(1.0, 4.94, -7.0),
+        expected_facing: Vec3::NEG_Y,
+    }; "ceiling keeps x and depth"
+)]
+fn test_update_depth_markers_projects_ball_onto_wall(case: UpdateDepthMarkersCase) {
+    let mut app = create_depth_marker_app();
+    spawn_marker_wall(&mut app, case.wall_translation, case.wall_half_extents);
+    let ball = spawn_ball(&mut app, Vec3::new(1.0, 2.0, -7.0));
+
+    app.update();
+
+    let markers = markers_of(&mut app, ball);
+    assert_eq!(markers.len(), 1);
+    let (_, transform) = markers[0];
+    assert!(
+        transform
+            .translation
+            .abs_diff_eq(case.expected_translation, 1e-4),
+        "{:?}",
+        transform.translation
+    );
+    assert!((transform.rotation * Vec3::Z).abs_diff_eq(case.expected_facing, 1e-4));
+}
+
+#[test]
+fn test_depth_markers_follow_each_ball() {
+    let mut app = create_depth_marker_app();
+    let left = spawn_marker_wall(
+        &mut app,
+        Vec3::new(-10.0, 0.0, 0.0),
+        Vec3::new(0.05, 5.0, 20.0),
+    );
+    let floor = spawn_marker_wall(
+        &mut app,
+        Vec3::new(0.0, -5.0, 0.0),
+        Vec3::new(10.0, 0.05, 20.0),
+    );
+    let first = spawn_ball(&mut app, Vec3::new(0.0, 0.0, -5.0));
+    app.update();
+    let second = spawn_ball(&mut app, Vec3::new(0.0, 0.0, 5.0));
+    app.update();
+
+    app.world_mut()
+        .get_mut::<Transform>(first)
+        .unwrap()
+        .translation
+        .z = 3.0;
+    app.update();
+
+    let first_markers = markers_of(&mut app, first);
+    let mut walls: Vec<Entity> = first_markers.iter().map(|(wall, _)| *wall).collect();
+    walls.sort();
+    let mut expected_walls = vec![left, floor];
+    expected_walls.sort();
+    assert_eq!(walls, expected_walls);
+    assert!(
+        first_markers
+            .iter()
+            .all(|(_, transform)| transform.translation.z == 3.0)
+    );
+    let second_markers = markers_of(&mut app, second);
+    assert_eq!(second_markers.len(), 2);
+    assert!(
+        second_markers
+            .iter()
+            .all(|(_, transform)| transform.translation.z == 5.0)
+    );
+}
+
+#[test]
+fn test_depth_markers_despawn_with_their_ball() {
+    let mut app = create_depth_marker_app();
+    spawn_marker_wall(
+        &mut app,
+        Vec3::new(-10.0, 0.0, 0.0),
+        Vec3::new(0.05, 5.0, 20.0),
+    );
+    let ball = spawn_ball(&mut app, Vec3::ZERO);
+    app.update();
+    assert_eq!(markers_of(&mut app, ball).len(), 1);
+
+    app.world_mut().despawn(ball);
+    app.update();
+
+    assert!(markers_of(&mut app, ball).is_empty());
+}
 
 struct WallCollisionHandlerCase {
     position: Vec3,
@@ -209,7 +409,7 @@ fn setup_wall_collision_case(app: &mut App, case: &WallCollisionHandlerCase) ->
 
 fn assert_vec3_eq(actual: Vec3, expected: Vec3, label: &str) {
     assert!(
-        (actual - expected).length() < EPSILON,
+        (actual - expected).length() < f32::EPSILON,
         "{}: expected {:?}, got {:?}",
         label,
         expected,
@@ -219,7 +419,7 @@ fn assert_vec3_eq(actual: Vec3, expected: Vec3, label: &str) {
 
 fn assert_vec2_eq(actual: Vec2, expected: Vec2, label: &str) {
     assert!(
-        (actual - expected).length() < EPSILON,
+        (actual - expected).length() < f32::EPSILON,
         "{}: expected {:?}, got {:?}",
         label,
         expected,
@@ -1,39 +1,306 @@
 use bevy::prelude::*;
 
+use crate::health;
+
 #[derive(Component)]
 pub struct Health {
     pub max: u8,
     pub current: u8,
 }
 
+/// Gradient the health color moves along, evenly spaced from the first stop at 1 health to the
+/// last at full health.
 #[derive(Component)]
 pub struct HealthColors {
-    pub max: LinearRgba,
-    pub min: LinearRgba,
+    pub stops: Vec<LinearRgba>,
+}
+
+impl HealthColors {
+    pub fn color_for(&self, health: &Health) -> Color {
+        // A single hit point has no range to spread the stops over
+        let t = if health.max <= 1 {
+            1.0
+        } else {
+            ((health.current as f32 - 1.0) / (health.max as f32 - 1.0)).clamp(0.0, 1.0)
+        };
+        let Some(last) = self.stops.len().checked_sub(1) else {
+            return Color::WHITE;
+        };
+        let scaled = t * last as f32;
+        let index = (scaled.floor() as usize).min(last.saturating_sub(1));
+        let next = (index + 1).min(last);
+        Color::from(self.stops[index].mix(&self.stops[next], scaled - index as f32))
+    }
+}
+
+/// Pulses the emissive channel while health is at or below `threshold` and no shield is up.
+#[derive(Component)]
+pub struct LowHealthPulse {
+    pub threshold: u8,
+    pub color: LinearRgba,
+    pub frequency: f32, // Pulses per second
+    pub active: bool,
+}
+
+impl LowHealthPulse {
+    pub fn new(threshold: u8, color: LinearRgba, frequency: f32) -> Self {
+        LowHealthPulse {
+            threshold,
+            color,
+            frequency,
+            active: false,
+        }
+    }
 }
 
+/// How an entity looks once health drops to `at` or below.
+#[derive(Clone)]
+pub struct DamageTier {
+    pub at: u8,
+    pub mesh: Handle<Mesh>,
+    pub texture: Option<Handle<Image>>,
+}
+
+/// Swaps mesh and texture to the most damaged tier that still applies, so a tier at max health
+/// is needed to get back the undamaged look.
+#[derive(Component)]
+pub struct DamageTiers {
+    pub tiers: Vec<DamageTier>,
+    pub active: Option<usize>,
+}
+
+impl DamageTiers {
+    pub fn new(tiers: Vec<DamageTier>) -> Self {
+        DamageTiers {
+            tiers,
+            active: None,
+        }
+    }
+
+    pub fn tier_for(&self, health: &Health) -> Option<usize> {
+        self.tiers
+            .iter()
+            .enumerate()
+            .filter(|(_, tier)| health.current <= tier.at)
+            .min_by_key(|(_, tier)| tier.at)
+            .map(|(index, _)| index)
+    }
+}
+
+/// Absorbs damage before `Health`, recharging once no damage has landed for
+/// `recharge_delay` seconds.
+#[derive(Component)]
+pub struct Shield {
+    pub max: u8,
+    pub current: u8,
+    pub recharge_delay: f32,
+    pub recharge_rate: f32, // Points per second
+    pub since_damage: f32,
+    pub recharge_progress: f32,
+}
+
+impl Shield {
+    pub fn new(max: u8, recharge_delay: f32, recharge_rate: f32) -> Self {
+        Shield {
+            max,
+            current: max,
+            recharge_delay,
+            recharge_rate,
+            since_damage: 0.0,
+            recharge_progress: 0.0,
+        }
+    }
+
+    /// Soaks up as much of a negative `delta` as it can, returning what gets through.
+    pub fn absorb(&mut self, delta: i16) -> i16 {
+        self.since_damage = 0.0;
+        self.recharge_progress = 0.0;
+        let absorbed = (self.current as i16).min(-delta);
+        self.current -= absorbed as u8;
+        delta + absorbed
+    }
+}
+
+/// Shown on the emissive channel, scaled by how much shield is left.
+#[derive(Component)]
+pub struct ShieldColor(pub LinearRgba);
+
+/// Flat reduction of every negative delta, applied before the shield.
+#[derive(Component)]
+pub struct Armor(pub u8);
+
+/// How a new over time effect combines with the one already running on an entity.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Stacking {
+    /// Restart the duration, keeping the stronger amount.
+    Refresh,
+    /// Add another stack and restart the duration.
+    Intensify { max_stacks: u8 },
+}
+
+/// Changes health by `amount` per stack every `interval` seconds, until `duration` runs out or
+/// forever without one.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct OverTime {
+    pub amount: u8,
+    pub interval: f32,
+    pub duration: Option<f32>,
+    pub stacking: Stacking,
+    pub stacks: u8,
+    pub elapsed: f32,
+    pub source: Option<Entity>,
+}
+
+impl OverTime {
+    pub fn new(amount: u8, interval: f32, duration: Option<f32>, stacking: Stacking) -> Self {
+        OverTime {
+            amount,
+            interval,
+            duration,
+            stacking,
+            stacks: 1,
+            elapsed: 0.0,
+            source: None,
+        }
+    }
+
+    pub fn stack(&mut self, incoming: &OverTime) {
+        match self.stacking {
+            Stacking::Refresh => {
+                self.amount = self.amount.max(incoming.amount);
+                self.duration = incoming.duration;
+                self.elapsed = 0.0;
+            }
+            Stacking::Intensify { max_stacks } => {
+                self.stacks = (self.stacks + 1).min(max_stacks);
+                self.duration = incoming.duration;
+                self.elapsed = 0.0;
+            }
+        }
+        self.source = incoming.source.or(self.source);
+    }
+
+    /// Advances by `delta` seconds, returning how many ticks landed and whether the effect ran out.
+    /// An effect without a positive interval never ticks.
+    pub fn advance(&mut self, delta: f32) -> (u32, bool) {
+        let start = self.elapsed;
+        self.elapsed += delta;
+        let end = self
+            .duration
+            .map_or(self.elapsed, |duration| self.elapsed.min(duration));
+        let ticks = if self.interval > 0.0 {
+            (end / self.interval).floor() as u32 - (start / self.interval).floor() as u32
+        } else {
+            0
+        };
+        let expired = self
+            .duration
+            .is_some_and(|duration| self.elapsed >= duration);
+        (ticks, expired)
+    }
+
+    pub fn per_tick(&self) -> i16 {
+        self.amount as i16 * self.stacks as i16
+    }
+}
+
+#[derive(Component)]
+pub struct Regeneration(pub OverTime);
+
+#[derive(Component)]
+pub struct DamageOverTime(pub OverTime);
+
+/// How a dying entity goes out, without one it is despawned as soon as it dies.
+#[derive(Component)]
+pub struct DeathEffect {
+    pub duration: f32,
+    pub fragments: u8,
+}
+
+/// Entity has died and is playing its death effect, it no longer collides or takes damage.
+#[derive(Component)]
+pub struct Dying {
+    pub elapsed: f32,
+    pub duration: f32,
+    pub finished: bool,
+}
+
+impl Dying {
+    pub fn new(duration: f32) -> Self {
+        Dying {
+            elapsed: 0.0,
+            duration,
+            finished: false,
+        }
+    }
+}
+
+/// Debris thrown off by a `DeathEffect`, itself fading out through `Dying`.
+#[derive(Component)]
+pub struct Fragment;
+
+/// Makes the entity `Invulnerable` for `duration` seconds whenever it takes damage.
+#[derive(Component)]
+pub struct InvulnerableOnDamage {
+    pub duration: f32,
+    pub blink_interval: f32,
+}
+
+/// Ignores damage while present, the entity blinks until `remaining` runs out.
+#[derive(Component)]
+pub struct Invulnerable {
+    pub remaining: f32,
+}
+
+/// Damage passes through these in order: invulnerability, armor, shield, then health.
+pub type HealthLayers<'a> = (
+    &'a mut Health,
+    Has<Dying>,
+    Has<Invulnerable>,
+    Option<&'a InvulnerableOnDamage>,
+    Option<&'a Armor>,
+    Option<&'a mut Shield>,
+);
+
+pub type InvulnerableBlink<'a> = (
+    Entity,
+    &'a mut Invulnerable,
+    &'a InvulnerableOnDamage,
+    Option<(&'a Health, &'a HealthColors)>,
+);
+
+pub type DamageTierLook<'a> = (
+    &'a Health,
+    &'a mut DamageTiers,
+    &'a mut Mesh3d,
+    Option<&'a mut MeshMaterial3d<StandardMaterial>>,
+);
+
+/// Marker components that `Affects` selectors can look entities up by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TargetMarker {
+    Player,
+}
+
+/// Who a `ChangeOnCollision` applies to. Marker selectors are resolved when the collision
+/// happens, so they follow entities spawned later.
 #[derive(Clone)]
 pub enum Affects {
     SelfOnly,
-    Others(Vec<Entity>),
-    #[allow(dead_code)]
-    SelfAndOthers(Vec<Entity>),
+    AllWith(TargetMarker),
 }
 
 #[derive(Component)]
 pub struct ChangeOnCollision {
     pub delta: i16,
+    pub kind: health::messages::DamageKind,
     pub affected: Affects,
 }
 
-impl ChangeOnCollision {
-    pub fn affected_entities(&self, entity: Entity) -> Box<dyn Iterator<Item = Entity> + '_> {
-        match &self.affected {
-            Affects::SelfOnly => Box::new(std::iter::once(entity)),
-            Affects::Others(others) => Box::new(others.iter().copied()),
-            Affects::SelfAndOthers(others) => {
-                Box::new(std::iter::once(entity).chain(others.iter().copied()))
-            }
-        }
-    }
+/// Starts or stacks an over time effect on whoever `affected` resolves to on collision.
+#[derive(Component)]
+pub struct OverTimeOnCollision {
+    pub kind: health::messages::OverTimeKind,
+    pub effect: OverTime,
+    pub affected: Affects,
 }
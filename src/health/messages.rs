@@ -1,12 +1,44 @@
 use bevy::prelude::*;
 
+use crate::health;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DamageKind {
+    Impact,
+    OverTime, // Regeneration and damage over time ticks
+}
+
 #[derive(Message, Copy, Clone, PartialEq, Debug)]
 pub struct HealChangedMessage {
     pub entity: Entity,
     pub delta: i16,
+    pub source: Option<Entity>, // What caused the change, e.g. the ball that hit a brick
+    pub kind: DamageKind,
 }
 
 #[derive(Message, Copy, Clone, PartialEq, Debug)]
 pub struct DeathMessage {
     pub entity: Entity,
+    pub source: Option<Entity>, // Source of the change that took the last health
+    pub kind: DamageKind,
+}
+
+/// Written once a dying entity's death effect has played out, right before it is despawned.
+#[derive(Message, Copy, Clone, PartialEq, Debug)]
+pub struct DeathFinishedMessage {
+    pub entity: Entity,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OverTimeKind {
+    Regeneration,
+    Damage,
+}
+
+/// Starts a regeneration or damage over time effect, stacking onto one that is already running.
+#[derive(Message, Copy, Clone, PartialEq, Debug)]
+pub struct ApplyOverTimeMessage {
+    pub entity: Entity,
+    pub kind: OverTimeKind,
+    pub effect: health::components::OverTime,
 }
@@ -12,10 +12,26 @@ mod tests;
 pub fn plugin(app: &mut App) {
     app.add_message::<messages::HealChangedMessage>()
         .add_message::<messages::DeathMessage>()
+        .add_message::<messages::DeathFinishedMessage>()
+        .add_message::<messages::ApplyOverTimeMessage>()
         .add_systems(
             Update,
-            (systems::handle_health_changed, systems::handle_death)
-                .run_if(in_state(states::GameState::Gameplay)),
+            (
+                (
+                    systems::apply_over_time,
+                    systems::tick_regeneration,
+                    systems::tick_damage_over_time,
+                )
+                    .chain()
+                    .before(systems::handle_health_changed),
+                systems::handle_health_changed,
+                systems::handle_death,
+                systems::spawn_death_fragments,
+                systems::update_dying,
+                systems::update_invulnerable,
+                systems::recharge_shields,
+            )
+                .run_if(in_state(states::PauseState::Running)),
         )
         .add_systems(
             FixedUpdate,
@@ -25,8 +41,15 @@ pub fn plugin(app: &mut App) {
         )
         .add_systems(
             PostUpdate,
-            systems::update_health_color
-                .before(crate::rendering::RenderingSet::Integrate)
+            (
+                systems::update_health_color,
+                systems::update_damage_tier,
+                (systems::pulse_low_health, systems::update_shield_color).chain(),
+            )
+                // Tweens they start take effect the same frame
+                .before(crate::rendering::RenderingSet::Tween)
                 .run_if(in_state(states::GameState::Gameplay)),
-        );
+        )
+        // After Update so every reader of the finished message can still see the entity
+        .add_systems(PostUpdate, systems::despawn_dead);
 }
@@ -1,80 +1,546 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use rand::Rng;
 
-use crate::{health, physics, rendering};
+use crate::gameplay::player;
+use crate::{health, physics, rendering, states};
+
+// Alpha of the dimmed phase of the invulnerability blink
+const INVULNERABLE_BLINK_ALPHA: f32 = 0.15;
+const FRAGMENT_SIZE: f32 = 0.3;
+const FRAGMENT_SPEED: f32 = 8.0;
+// How long a low health pulse takes to fade out once it stops
+const LOW_HEALTH_FADE_SECONDS: f32 = 0.15;
+// How long the health color takes to move along its gradient after a change
+const HEALTH_COLOR_FADE_SECONDS: f32 = 0.2;
 
 pub fn handle_health_changed(
+    mut commands: Commands,
     mut health_changed_messages: MessageReader<health::messages::HealChangedMessage>,
     mut death_messages: MessageWriter<health::messages::DeathMessage>,
-    mut health_query: Query<&mut health::components::Health>,
+    mut health_query: Query<health::components::HealthLayers>,
 ) {
+    // Invulnerability is inserted through commands, so also track damage landing this frame
+    let mut damaged = bevy::platform::collections::HashSet::new();
     for message in health_changed_messages.read() {
-        if let Ok(mut health) = health_query.get_mut(message.entity) {
-            let new_health =
-                (health.current as i16 + message.delta).clamp(0, health.max as i16) as u8;
+        if let Ok((mut health, dying, invulnerable, invulnerable_on_damage, armor, shield)) =
+            health_query.get_mut(message.entity)
+        {
+            if dying {
+                continue;
+            }
+            let mut delta = message.delta;
+            if delta < 0 {
+                if invulnerable || damaged.contains(&message.entity) {
+                    continue;
+                }
+                if let Some(armor) = armor {
+                    delta = (delta + armor.0 as i16).min(0);
+                }
+                if let Some(mut shield) = shield
+                    && delta < 0
+                {
+                    delta = shield.absorb(delta);
+                }
+                if delta == 0 {
+                    continue;
+                }
+                // Ticks land on their own schedule, granting i-frames would let them cancel out
+                if let Some(invulnerable_on_damage) = invulnerable_on_damage
+                    && message.kind != health::messages::DamageKind::OverTime
+                {
+                    damaged.insert(message.entity);
+                    commands
+                        .entity(message.entity)
+                        .insert(health::components::Invulnerable {
+                            remaining: invulnerable_on_damage.duration,
+                        });
+                }
+            }
+
+            let new_health = (health.current as i16 + delta).clamp(0, health.max as i16) as u8;
             health.current = new_health;
             if health.current == 0 {
                 death_messages.write(health::messages::DeathMessage {
                     entity: message.entity,
+                    source: message.source,
+                    kind: message.kind,
                 });
             }
         }
     }
 }
 
+pub fn apply_over_time(
+    mut commands: Commands,
+    mut messages: MessageReader<health::messages::ApplyOverTimeMessage>,
+    mut query: Query<(
+        Option<&mut health::components::Regeneration>,
+        Option<&mut health::components::DamageOverTime>,
+    )>,
+) {
+    for message in messages.read() {
+        let Ok((regeneration, damage_over_time)) = query.get_mut(message.entity) else {
+            continue;
+        };
+        match message.kind {
+            health::messages::OverTimeKind::Regeneration => match regeneration {
+                Some(mut regeneration) => regeneration.0.stack(&message.effect),
+                None => {
+                    commands
+                        .entity(message.entity)
+                        .insert(health::components::Regeneration(message.effect));
+                }
+            },
+            health::messages::OverTimeKind::Damage => match damage_over_time {
+                Some(mut damage_over_time) => damage_over_time.0.stack(&message.effect),
+                None => {
+                    commands
+                        .entity(message.entity)
+                        .insert(health::components::DamageOverTime(message.effect));
+                }
+            },
+        }
+    }
+}
+
+pub fn tick_regeneration(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut health::components::Regeneration)>,
+    mut health_changed_messages: MessageWriter<health::messages::HealChangedMessage>,
+) {
+    for (entity, mut regeneration) in &mut query {
+        let expired = write_over_time_ticks(
+            entity,
+            &mut regeneration.0,
+            1,
+            time.delta_secs(),
+            &mut health_changed_messages,
+        );
+        if expired {
+            commands
+                .entity(entity)
+                .remove::<health::components::Regeneration>();
+        }
+    }
+}
+
+pub fn tick_damage_over_time(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut health::components::DamageOverTime)>,
+    mut health_changed_messages: MessageWriter<health::messages::HealChangedMessage>,
+) {
+    for (entity, mut damage_over_time) in &mut query {
+        let expired = write_over_time_ticks(
+            entity,
+            &mut damage_over_time.0,
+            -1,
+            time.delta_secs(),
+            &mut health_changed_messages,
+        );
+        if expired {
+            commands
+                .entity(entity)
+                .remove::<health::components::DamageOverTime>();
+        }
+    }
+}
+
+fn write_over_time_ticks(
+    entity: Entity,
+    effect: &mut health::components::OverTime,
+    sign: i16,
+    delta: f32,
+    health_changed_messages: &mut MessageWriter<health::messages::HealChangedMessage>,
+) -> bool {
+    let (ticks, expired) = effect.advance(delta);
+    for _ in 0..ticks {
+        health_changed_messages.write(health::messages::HealChangedMessage {
+            entity,
+            delta: sign * effect.per_tick(),
+            source: effect.source,
+            kind: health::messages::DamageKind::OverTime,
+        });
+    }
+    expired
+}
+
+// The entity stays around while its death effect plays, so systems reading the message
+// later in the frame can still look it up
 pub fn handle_death(
     mut messages: MessageReader<health::messages::DeathMessage>,
     mut commands: Commands,
+    query: Query<(Has<Transform>, Option<&health::components::DeathEffect>)>,
+    mut tween_messages: MessageWriter<rendering::messages::TweenMessage>,
+) {
+    for message in messages.read() {
+        debug!(
+            "{} died to {:?} from {:?}",
+            message.entity, message.kind, message.source
+        );
+        let Ok((has_transform, death_effect)) = query.get(message.entity) else {
+            continue;
+        };
+        let duration = death_effect.map_or(0.0, |effect| effect.duration);
+        commands
+            .entity(message.entity)
+            .remove::<physics::components::BoundingCuboid>()
+            .insert(health::components::Dying::new(duration));
+        if has_transform && duration > 0.0 {
+            tween_messages.write(shrink_to_nothing(message.entity, duration));
+        }
+    }
+}
+
+fn shrink_to_nothing(entity: Entity, duration: f32) -> rendering::messages::TweenMessage {
+    rendering::messages::TweenMessage {
+        entity,
+        target: rendering::components::TweenTarget::Scale(Vec3::ZERO),
+        duration,
+        easing: EaseFunction::Linear,
+        repeat: rendering::components::TweenRepeat::Once,
+    }
+}
+
+pub fn spawn_death_fragments(
+    mut messages: MessageReader<health::messages::DeathMessage>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut fragment_mesh: Local<Option<Handle<Mesh>>>,
+    query: Query<(
+        &GlobalTransform,
+        &health::components::DeathEffect,
+        Option<&MeshMaterial3d<StandardMaterial>>,
+    )>,
+    mut tween_messages: MessageWriter<rendering::messages::TweenMessage>,
+) {
+    let mut rng = rand::rng();
+    for message in messages.read() {
+        let Ok((transform, death_effect, material)) = query.get(message.entity) else {
+            continue;
+        };
+        if death_effect.fragments == 0 {
+            continue;
+        }
+
+        let mesh = fragment_mesh
+            .get_or_insert_with(|| meshes.add(Cuboid::from_length(FRAGMENT_SIZE)))
+            .clone();
+        for _ in 0..death_effect.fragments {
+            let direction = Vec3::new(
+                rng.random_range(-1.0..1.0),
+                rng.random_range(-1.0..1.0),
+                rng.random_range(-1.0..1.0),
+            )
+            .normalize_or(Vec3::Y);
+            let mut fragment = commands.spawn((
+                health::components::Fragment,
+                Name::new("Fragment"),
+                Mesh3d(mesh.clone()),
+                Transform::from_translation(transform.translation()),
+                health::components::Dying::new(death_effect.duration),
+                DespawnOnExit(states::GameState::Gameplay),
+            ));
+            if let Some(material) = material {
+                fragment.insert(material.clone());
+            }
+
+            // Thrown out fast, slowing down while shrinking away
+            let entity = fragment.id();
+            let distance = rng.random_range(2.0..FRAGMENT_SPEED) * death_effect.duration;
+            tween_messages.write(rendering::messages::TweenMessage {
+                entity,
+                target: rendering::components::TweenTarget::Translation(
+                    transform.translation() + direction * distance,
+                ),
+                duration: death_effect.duration,
+                easing: EaseFunction::QuadraticOut,
+                repeat: rendering::components::TweenRepeat::Once,
+            });
+            tween_messages.write(shrink_to_nothing(entity, death_effect.duration));
+        }
+    }
+}
+
+/// Plays out death effects, the shrink itself runs as a tween started on death.
+pub fn update_dying(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut health::components::Dying)>,
+    mut finished_messages: MessageWriter<health::messages::DeathFinishedMessage>,
+) {
+    for (entity, mut dying) in &mut query {
+        if dying.finished {
+            continue;
+        }
+        dying.elapsed += time.delta_secs();
+        if dying.elapsed >= dying.duration {
+            dying.finished = true;
+            finished_messages.write(health::messages::DeathFinishedMessage { entity });
+        }
+    }
+}
+
+pub fn despawn_dead(
+    mut messages: MessageReader<health::messages::DeathFinishedMessage>,
+    mut commands: Commands,
 ) {
     for message in messages.read() {
-        commands.entity(message.entity).despawn();
+        if let Ok(mut entity) = commands.get_entity(message.entity) {
+            entity.despawn();
+        }
     }
 }
 
 pub fn update_health_color(
+    mut query: Query<
+        (
+            Entity,
+            &health::components::Health,
+            &health::components::HealthColors,
+        ),
+        // The blink owns the color while invulnerable
+        Without<health::components::Invulnerable>,
+    >,
+    mut health_changed_messages: MessageReader<health::messages::HealChangedMessage>,
+    mut tween_messages: MessageWriter<rendering::messages::TweenMessage>,
+) {
+    for message in health_changed_messages.read() {
+        if let Ok((entity, health, health_colors)) = query.get_mut(message.entity) {
+            if health.current == 0 {
+                continue;
+            }
+            let new_color = health_colors.color_for(health);
+            tween_messages.write(rendering::messages::TweenMessage {
+                entity,
+                target: rendering::components::TweenTarget::BaseColor(new_color.to_linear()),
+                duration: HEALTH_COLOR_FADE_SECONDS,
+                easing: EaseFunction::SineInOut,
+                repeat: rendering::components::TweenRepeat::Once,
+            });
+        }
+    }
+}
+
+pub fn pulse_low_health(
     mut query: Query<(
         Entity,
         &health::components::Health,
-        &health::components::HealthColors,
+        &mut health::components::LowHealthPulse,
+        Option<&health::components::Shield>,
     )>,
-    mut health_changed_messages: MessageReader<health::messages::HealChangedMessage>,
+    mut tween_messages: MessageWriter<rendering::messages::TweenMessage>,
+) {
+    for (entity, health, mut pulse, shield) in &mut query {
+        // The shield owns the emissive channel while it is up
+        let low = health.current > 0
+            && health.current <= pulse.threshold
+            && shield.is_none_or(|shield| shield.current == 0);
+        if low == pulse.active {
+            continue;
+        }
+        pulse.active = low;
+        tween_messages.write(if low {
+            // Each run of the ping pong is half a pulse
+            rendering::messages::TweenMessage {
+                entity,
+                target: rendering::components::TweenTarget::Emissive(pulse.color),
+                duration: 0.5 / pulse.frequency,
+                easing: EaseFunction::SineInOut,
+                repeat: rendering::components::TweenRepeat::PingPong,
+            }
+        } else {
+            rendering::messages::TweenMessage {
+                entity,
+                target: rendering::components::TweenTarget::Emissive(LinearRgba::BLACK),
+                duration: LOW_HEALTH_FADE_SECONDS,
+                easing: EaseFunction::SineInOut,
+                repeat: rendering::components::TweenRepeat::Once,
+            }
+        });
+    }
+}
+
+pub fn update_damage_tier(
+    mut query: Query<health::components::DamageTierLook, Changed<health::components::Health>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut material_cache: ResMut<rendering::resources::MaterialCache>,
+) {
+    for (health, mut damage_tiers, mut mesh, material) in &mut query {
+        let tier = damage_tiers.tier_for(health);
+        if tier == damage_tiers.active {
+            continue;
+        }
+        damage_tiers.active = tier;
+        let Some(tier) = tier.map(|index| &damage_tiers.tiers[index]) else {
+            continue;
+        };
+
+        mesh.0 = tier.mesh.clone();
+        if let Some(mut material) = material
+            && let Some(variant) = material_cache.variant(&mut materials, &material.0, |variant| {
+                variant.base_color_texture = tier.texture.clone();
+            })
+        {
+            material.0 = variant;
+        }
+    }
+}
+
+pub fn recharge_shields(time: Res<Time>, mut query: Query<&mut health::components::Shield>) {
+    for mut shield in &mut query {
+        // Timers tick every frame, only a change in points should count as a change
+        let recharged = {
+            let shield = shield.bypass_change_detection();
+            shield.since_damage += time.delta_secs();
+            if shield.current >= shield.max || shield.since_damage < shield.recharge_delay {
+                continue;
+            }
+
+            shield.recharge_progress += shield.recharge_rate * time.delta_secs();
+            let points = (shield.recharge_progress.floor() as u8).min(shield.max - shield.current);
+            shield.recharge_progress -= points as f32;
+            shield.current += points;
+            if shield.current == shield.max {
+                shield.recharge_progress = 0.0;
+            }
+            points > 0
+        };
+        if recharged {
+            shield.set_changed();
+        }
+    }
+}
+
+pub fn update_shield_color(
+    query: Query<
+        (
+            Entity,
+            &health::components::Shield,
+            &health::components::ShieldColor,
+        ),
+        Changed<health::components::Shield>,
+    >,
     mut material_colors_changed_messages: MessageWriter<
         rendering::messages::MaterialColorsChangedMessage,
     >,
 ) {
-    for message in health_changed_messages.read() {
-        if let Ok((entity, health, health_colors)) = query.get_mut(message.entity) {
-            if health.current == 0 {
-                continue;
-            }
-            let t = ((health.current as f32 - 1.0) / (health.max as f32 - 1.0)).clamp(0.0, 1.0);
-            let new_color = Color::from(health_colors.min.mix(&health_colors.max, t));
-            material_colors_changed_messages.write(
-                rendering::messages::MaterialColorsChangedMessage {
-                    entity,
-                    base_color: Some(new_color),
-                    emissive: None,
-                },
-            );
+    for (entity, shield, shield_color) in &query {
+        let strength = if shield.max == 0 {
+            0.0
+        } else {
+            shield.current as f32 / shield.max as f32
+        };
+        material_colors_changed_messages.write(rendering::messages::MaterialColorsChangedMessage {
+            entity,
+            base_color: None,
+            emissive: Some((shield_color.0 * strength).with_alpha(shield_color.0.alpha)),
+        });
+    }
+}
+
+pub fn update_invulnerable(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<health::components::InvulnerableBlink>,
+    mut tween_messages: MessageWriter<rendering::messages::TweenMessage>,
+) {
+    for (entity, mut invulnerable, invulnerable_on_damage, health) in &mut query {
+        // Even phases, counted from when damage landed, are dimmed
+        let dimmed = |remaining: f32| {
+            let elapsed = invulnerable_on_damage.duration - remaining;
+            (elapsed / invulnerable_on_damage.blink_interval).floor() as i32 % 2 == 0
+        };
+        let started = invulnerable.is_added();
+        let was_dimmed = dimmed(invulnerable.remaining);
+        invulnerable.remaining -= time.delta_secs();
+
+        let expired = invulnerable.remaining <= 0.0;
+        if expired {
+            commands
+                .entity(entity)
+                .remove::<health::components::Invulnerable>();
+        }
+
+        let is_dimmed = dimmed(invulnerable.remaining) && !expired;
+        let Some((health, health_colors)) = health else {
+            continue;
+        };
+        if is_dimmed == was_dimmed && !started && !expired {
+            continue;
+        }
+
+        let color = health_colors.color_for(health);
+        let base_color = if is_dimmed {
+            color.with_alpha(INVULNERABLE_BLINK_ALPHA)
+        } else {
+            color
+        };
+        // Snaps without a duration, replacing any health color fade still running
+        tween_messages.write(rendering::messages::TweenMessage {
+            entity,
+            target: rendering::components::TweenTarget::BaseColor(base_color.to_linear()),
+            duration: 0.0,
+            easing: EaseFunction::Linear,
+            repeat: rendering::components::TweenRepeat::Once,
+        });
+    }
+}
+
+/// Looks up the entities an `Affects` selector refers to.
+#[derive(SystemParam)]
+pub struct AffectedTargets<'w, 's> {
+    players: Query<'w, 's, Entity, With<player::components::Player>>,
+}
+
+impl AffectedTargets<'_, '_> {
+    pub fn resolve(&self, entity: Entity, affects: &health::components::Affects) -> Vec<Entity> {
+        match affects {
+            health::components::Affects::SelfOnly => vec![entity],
+            health::components::Affects::AllWith(marker) => match marker {
+                health::components::TargetMarker::Player => self.players.iter().collect(),
+            },
         }
     }
 }
 
 pub fn handle_collision(
     collided_query: Query<&health::components::ChangeOnCollision>,
+    over_time_query: Query<&health::components::OverTimeOnCollision>,
+    targets: AffectedTargets,
     health_query: Query<&health::components::Health>,
     mut collision_messages: MessageReader<physics::messages::CollisionMessage>,
     mut health_changed_messages: MessageWriter<health::messages::HealChangedMessage>,
+    mut over_time_messages: MessageWriter<health::messages::ApplyOverTimeMessage>,
 ) {
     for message in collision_messages.read() {
-        for &entity in [message.a, message.b].iter() {
+        for (entity, other) in [(message.a, message.b), (message.b, message.a)] {
             if let Ok(change_on_collision) = collided_query.get(entity) {
-                for target in change_on_collision.affected_entities(entity) {
+                for target in targets.resolve(entity, &change_on_collision.affected) {
                     if !health_query.contains(target) {
                         continue;
                     }
                     health_changed_messages.write(health::messages::HealChangedMessage {
                         entity: target,
                         delta: change_on_collision.delta,
+                        source: Some(other),
+                        kind: change_on_collision.kind,
+                    });
+                }
+            }
+            if let Ok(over_time_on_collision) = over_time_query.get(entity) {
+                for target in targets.resolve(entity, &over_time_on_collision.affected) {
+                    if !health_query.contains(target) {
+                        continue;
+                    }
+                    over_time_messages.write(health::messages::ApplyOverTimeMessage {
+                        entity: target,
+                        kind: over_time_on_collision.kind,
+                        effect: health::components::OverTime {
+                            source: Some(other),
+                            ..over_time_on_collision.effect
+                        },
                     });
                 }
             }
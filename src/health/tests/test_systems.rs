@@ -2,7 +2,7 @@ use bevy::prelude::*;
 use test_case::test_case;
 
 use crate::health::{components, messages, systems};
-use crate::{physics, rendering, test_utils};
+use crate::{gameplay, physics, rendering, test_utils};
 
 fn create_health_change_app() -> App {
     let mut app = App::new();
@@ -48,9 +48,12 @@ fn test_health_change(case: HealthChangedCase) {
     let mut app = create_health_change_app();
     let entity = app.world_mut().spawn(case.starting_health).id();
 
+    let source = app.world_mut().spawn_empty().id();
     app.world_mut().write_message(messages::HealChangedMessage {
         entity,
         delta: case.delta,
+        source: Some(source),
+        kind: messages::DamageKind::Impact,
     });
     app.update();
 
@@ -58,7 +61,11 @@ fn test_health_change(case: HealthChangedCase) {
     assert_eq!(health.current, case.expected_current);
 
     let expected = if case.expected_current == 0 {
-        vec![messages::DeathMessage { entity }]
+        vec![messages::DeathMessage {
+            entity,
+            source: Some(source),
+            kind: messages::DamageKind::Impact,
+        }]
     } else {
         vec![]
     };
@@ -66,15 +73,46 @@ fn test_health_change(case: HealthChangedCase) {
 }
 
 #[test]
-fn test_death_message_removes_entity() {
+fn test_death_message_starts_dying() {
     let mut app = create_death_app();
-    let entity = app.world_mut().spawn_empty().id();
+    let entity = app
+        .world_mut()
+        .spawn((
+            Transform::from_scale(Vec3::splat(2.0)),
+            physics::components::BoundingCuboid {
+                half_extents: Vec3::ONE,
+            },
+            components::DeathEffect {
+                duration: 0.5,
+                fragments: 0,
+            },
+        ))
+        .id();
 
-    app.world_mut()
-        .write_message(messages::DeathMessage { entity });
+    app.world_mut().write_message(messages::DeathMessage {
+        entity,
+        source: None,
+        kind: messages::DamageKind::Impact,
+    });
     app.update();
 
-    assert!(app.world().get_entity(entity).is_err());
+    let dying = app.world().get::<components::Dying>(entity).unwrap();
+    assert_eq!(dying.duration, 0.5);
+    test_utils::assertions::assert_messages(
+        &app,
+        &[rendering::messages::TweenMessage {
+            entity,
+            target: rendering::components::TweenTarget::Scale(Vec3::ZERO),
+            duration: 0.5,
+            easing: EaseFunction::Linear,
+            repeat: rendering::components::TweenRepeat::Once,
+        }],
+    );
+    assert!(
+        app.world()
+            .get::<physics::components::BoundingCuboid>(entity)
+            .is_none()
+    );
 }
 
 #[test]
@@ -85,15 +123,181 @@ fn test_no_death_message_keeps_entity_alive() {
     app.update();
 
     assert!(app.world().get_entity(entity).is_ok());
+    assert!(app.world().get::<components::Dying>(entity).is_none());
+}
+
+#[test]
+fn test_death_spawns_fragments() {
+    let mut app = create_death_app();
+    app.init_resource::<Assets<Mesh>>();
+    app.add_systems(Update, systems::spawn_death_fragments);
+    let entity = app
+        .world_mut()
+        .spawn((
+            GlobalTransform::default(),
+            components::DeathEffect {
+                duration: 0.5,
+                fragments: 4,
+            },
+        ))
+        .id();
+
+    app.world_mut().write_message(messages::DeathMessage {
+        entity,
+        source: None,
+        kind: messages::DamageKind::Impact,
+    });
+    app.update();
+
+    let fragments = app
+        .world_mut()
+        .query_filtered::<(), (With<components::Fragment>, With<components::Dying>)>()
+        .iter(app.world())
+        .count();
+    assert_eq!(fragments, 4);
+    // Each fragment flies out and shrinks, the dead entity itself has no transform to shrink
+    test_utils::assertions::assert_message_count::<rendering::messages::TweenMessage>(&app, 8);
 }
 
 fn create_death_app() -> App {
     let mut app = App::new();
     app.add_message::<messages::DeathMessage>()
+        .add_message::<rendering::messages::TweenMessage>()
         .add_systems(Update, systems::handle_death);
     app
 }
 
+struct UpdateDyingCase {
+    elapsed: f32,
+    duration: f32,
+    finished: bool,
+    advance: f32,
+    expected_finished: bool,
+}
+
+#[test_case(
+    UpdateDyingCase {
+        elapsed: 0.0,
+        duration: 0.5,
+        finished: false,
+        advance: 0.25,
+        expected_finished: false,
+    }; "keeps playing while the effect runs"
+)]
+#[test_case(
+    UpdateDyingCase {
+        elapsed: 0.25,
+        duration: 0.5,
+        finished: false,
+        advance: 0.5,
+        expected_finished: true,
+    }; "finishes when the effect runs out"
+)]
+#[test_case(
+    UpdateDyingCase {
+        elapsed: 0.5,
+        duration: 0.5,
+        finished: true,
+        advance: 0.25,
+        expected_finished: false,
+    }; "finished entities are left alone"
+)]
+#[test_case(
+    UpdateDyingCase {
+        elapsed: 0.0,
+        duration: 0.0,
+        finished: false,
+        advance: 0.0,
+        expected_finished: true,
+    }; "without a death effect it finishes on the first frame"
+)]
+fn test_update_dying(case: UpdateDyingCase) {
+    let mut app = App::new();
+    app.add_message::<messages::DeathFinishedMessage>()
+        .add_systems(Update, systems::update_dying);
+    app.insert_resource(Time::<()>::default());
+    let entity = app
+        .world_mut()
+        .spawn(components::Dying {
+            elapsed: case.elapsed,
+            duration: case.duration,
+            finished: case.finished,
+        })
+        .id();
+
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(std::time::Duration::from_secs_f32(case.advance));
+    app.update();
+
+    let expected = if case.expected_finished {
+        vec![messages::DeathFinishedMessage { entity }]
+    } else {
+        vec![]
+    };
+    test_utils::assertions::assert_messages(&app, &expected);
+}
+
+#[test]
+fn test_death_without_effect_despawns_next_frame() {
+    let mut app = App::new();
+    app.add_message::<messages::DeathMessage>()
+        .add_message::<messages::DeathFinishedMessage>()
+        .add_message::<rendering::messages::TweenMessage>()
+        .insert_resource(Time::<()>::default())
+        .add_systems(
+            Update,
+            (systems::handle_death, systems::update_dying).chain(),
+        )
+        .add_systems(PostUpdate, systems::despawn_dead);
+    let entity = app.world_mut().spawn(Transform::default()).id();
+
+    app.world_mut().write_message(messages::DeathMessage {
+        entity,
+        source: None,
+        kind: messages::DamageKind::Impact,
+    });
+    app.update();
+
+    assert!(app.world().get_entity(entity).is_err());
+}
+
+#[test]
+fn test_despawn_dead_removes_entity() {
+    let mut app = App::new();
+    app.add_message::<messages::DeathFinishedMessage>()
+        .add_systems(Update, systems::despawn_dead);
+    let entity = app.world_mut().spawn_empty().id();
+
+    app.world_mut()
+        .write_message(messages::DeathFinishedMessage { entity });
+    app.update();
+
+    assert!(app.world().get_entity(entity).is_err());
+}
+
+#[test]
+fn test_dying_entities_ignore_health_changes() {
+    let mut app = create_health_change_app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            components::Health { max: 3, current: 0 },
+            components::Dying::new(0.5),
+        ))
+        .id();
+
+    app.world_mut().write_message(messages::HealChangedMessage {
+        entity,
+        delta: -1,
+        source: None,
+        kind: messages::DamageKind::Impact,
+    });
+    app.update();
+
+    test_utils::assertions::assert_messages::<messages::DeathMessage>(&app, &[]);
+}
+
 struct UpdateHealthColorCase {
     health: components::Health,
     expected_color: Option<Color>,
@@ -129,39 +333,52 @@ struct UpdateHealthColorCase {
 fn test_update_health_color(case: UpdateHealthColorCase) {
     let mut app = App::new();
     app.add_message::<messages::HealChangedMessage>();
-    app.add_message::<rendering::messages::MaterialColorsChangedMessage>();
+    app.add_message::<rendering::messages::TweenMessage>();
     app.add_systems(Update, systems::update_health_color);
     let entity = app
         .world_mut()
         .spawn((
             case.health,
             components::HealthColors {
-                max: LinearRgba::rgb(0.0, 1.0, 0.0),
-                min: LinearRgba::rgb(1.0, 0.0, 0.0),
+                stops: vec![
+                    LinearRgba::rgb(1.0, 0.0, 0.0),
+                    LinearRgba::rgb(0.0, 1.0, 0.0),
+                ],
             },
         ))
         .id();
     let mut writer = app
         .world_mut()
         .resource_mut::<Messages<messages::HealChangedMessage>>();
-    writer.write(messages::HealChangedMessage { entity, delta: 0 });
+    writer.write(messages::HealChangedMessage {
+        entity,
+        delta: 0,
+        source: None,
+        kind: messages::DamageKind::Impact,
+    });
     app.update();
 
-    let mut expected: Vec<rendering::messages::MaterialColorsChangedMessage> = vec![];
-    if case.expected_color.is_some() {
-        expected.push(rendering::messages::MaterialColorsChangedMessage {
-            entity,
-            base_color: case.expected_color,
-            emissive: None,
-        })
-    };
+    let expected: Vec<rendering::messages::TweenMessage> = case
+        .expected_color
+        .map(|color| health_color_fade(entity, color))
+        .into_iter()
+        .collect();
     test_utils::assertions::assert_messages(&app, &expected);
 }
 
+fn health_color_fade(entity: Entity, color: Color) -> rendering::messages::TweenMessage {
+    rendering::messages::TweenMessage {
+        entity,
+        target: rendering::components::TweenTarget::BaseColor(color.to_linear()),
+        duration: 0.2,
+        easing: EaseFunction::SineInOut,
+        repeat: rendering::components::TweenRepeat::Once,
+    }
+}
+
 enum Target {
     SelfOnly,
-    Others,
-    SelfAndOthers,
+    AllPlayers,
 }
 
 struct HandleCollisionCase {
@@ -176,17 +393,13 @@ struct HandleCollisionCase {
     }; "self only")]
 #[test_case(
     HandleCollisionCase {
-        target: Target::Others,
-        delta: -1
-    }; "others only")]
-#[test_case(
-    HandleCollisionCase {
-        target: Target::SelfAndOthers,
+        target: Target::AllPlayers,
         delta: -1
-    }; "self and others")]
+    }; "all players")]
 fn test_handle_collision(case: HandleCollisionCase) {
     let mut app = App::new();
     app.add_message::<messages::HealChangedMessage>();
+    app.add_message::<messages::ApplyOverTimeMessage>();
     app.add_message::<physics::messages::CollisionMessage>();
     app.add_systems(Update, systems::handle_collision);
 
@@ -198,31 +411,27 @@ fn test_handle_collision(case: HandleCollisionCase) {
 
     let potential_target_entity = app
         .world_mut()
-        .spawn(components::Health { current: 1, max: 1 })
+        .spawn((
+            components::Health { current: 1, max: 1 },
+            gameplay::player::components::Player {},
+        ))
         .id();
-    let affects = match case.target {
-        Target::SelfOnly => components::Affects::SelfOnly,
-        Target::Others => components::Affects::Others(vec![potential_target_entity]),
-        Target::SelfAndOthers => components::Affects::SelfAndOthers(vec![potential_target_entity]),
+    let (affects, affected_targets) = match case.target {
+        Target::SelfOnly => (components::Affects::SelfOnly, vec![collision_b_entity]),
+        Target::AllPlayers => (
+            components::Affects::AllWith(components::TargetMarker::Player),
+            vec![potential_target_entity],
+        ),
     };
 
     app.world_mut()
         .entity_mut(collision_b_entity)
         .insert(components::ChangeOnCollision {
             delta: case.delta,
-            affected: affects.clone(),
+            kind: messages::DamageKind::Impact,
+            affected: affects,
         });
 
-    let affected_targets = match affects {
-        components::Affects::SelfOnly => vec![collision_b_entity],
-        components::Affects::Others(ref v) => v.clone(),
-        components::Affects::SelfAndOthers(ref v) => {
-            let mut vec = vec![collision_b_entity];
-            vec.extend(v.iter().copied());
-            vec
-        }
-    };
-
     let mut writer = app
         .world_mut()
         .resource_mut::<Messages<physics::messages::CollisionMessage>>();
@@ -241,8 +450,964 @@ fn test_handle_collision(case: HandleCollisionCase) {
         .map(|e| messages::HealChangedMessage {
             entity: e,
             delta: case.delta,
+            // The other party of the collision is credited as the source
+            source: Some(collision_a_entity),
+            kind: messages::DamageKind::Impact,
+        })
+        .collect();
+
+    test_utils::assertions::assert_messages(&app, &expected);
+}
+
+#[test]
+fn test_handle_collision_finds_players_spawned_later() {
+    let mut app = App::new();
+    app.add_message::<messages::HealChangedMessage>();
+    app.add_message::<messages::ApplyOverTimeMessage>();
+    app.add_message::<physics::messages::CollisionMessage>();
+    app.add_systems(Update, systems::handle_collision);
+
+    let health = || components::Health { current: 1, max: 1 };
+    let ball = app.world_mut().spawn_empty().id();
+    let collided = app
+        .world_mut()
+        .spawn((
+            health(),
+            components::ChangeOnCollision {
+                delta: -1,
+                kind: messages::DamageKind::Impact,
+                affected: components::Affects::AllWith(components::TargetMarker::Player),
+            },
+        ))
+        .id();
+    let player = app
+        .world_mut()
+        .spawn((health(), gameplay::player::components::Player {}))
+        .id();
+    app.update();
+
+    // Joins after the selector was set up, still found when the collision happens
+    let late_player = app
+        .world_mut()
+        .spawn((health(), gameplay::player::components::Player {}))
+        .id();
+    app.world_mut()
+        .write_message(physics::messages::CollisionMessage {
+            a: ball,
+            b: collided,
+            normal: Vec3::ZERO,
+            contact_point: Vec3::ZERO,
+            penetration: 0.0,
+        });
+
+    app.update();
+
+    let mut targets: Vec<Entity> = app
+        .world()
+        .resource::<Messages<messages::HealChangedMessage>>()
+        .iter_current_update_messages()
+        .map(|message| message.entity)
+        .collect();
+    targets.sort();
+    let mut expected_targets = vec![player, late_player];
+    expected_targets.sort();
+    assert_eq!(targets, expected_targets);
+}
+
+#[test_case(-1, 3; "damage is ignored")]
+#[test_case(1, 4; "healing still applies")]
+fn test_health_change_while_invulnerable(delta: i16, expected_current: u8) {
+    let mut app = create_health_change_app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            components::Health { max: 5, current: 3 },
+            components::Invulnerable { remaining: 1.0 },
+        ))
+        .id();
+
+    app.world_mut().write_message(messages::HealChangedMessage {
+        entity,
+        delta,
+        source: None,
+        kind: messages::DamageKind::Impact,
+    });
+    app.update();
+
+    let health = app.world().get::<components::Health>(entity).unwrap();
+    assert_eq!(health.current, expected_current);
+}
+
+#[test]
+fn test_damage_grants_invulnerability_and_absorbs_repeated_hits() {
+    let mut app = create_health_change_app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            components::Health { max: 3, current: 3 },
+            components::InvulnerableOnDamage {
+                duration: 1.5,
+                blink_interval: 0.1,
+            },
+        ))
+        .id();
+
+    // Several touches in the same frame only cost one health
+    for _ in 0..3 {
+        app.world_mut().write_message(messages::HealChangedMessage {
+            entity,
+            delta: -1,
+            source: None,
+            kind: messages::DamageKind::Impact,
+        });
+    }
+    app.update();
+
+    let health = app.world().get::<components::Health>(entity).unwrap();
+    assert_eq!(health.current, 2);
+    let invulnerable = app.world().get::<components::Invulnerable>(entity).unwrap();
+    assert_eq!(invulnerable.remaining, 1.5);
+}
+
+struct UpdateInvulnerableCase {
+    remaining: f32,
+    advance: f32,
+    expected_color: Option<Color>,
+    expected_invulnerable: bool,
+}
+
+#[test_case(
+    UpdateInvulnerableCase {
+        remaining: 1.5,
+        advance: 0.05,
+        expected_color: Some(Color::linear_rgba(0.0, 1.0, 0.0, 0.15)),
+        expected_invulnerable: true,
+    }; "dims as soon as damage lands"
+)]
+#[test_case(
+    UpdateInvulnerableCase {
+        remaining: 1.3,
+        advance: 0.05,
+        expected_color: None,
+        expected_invulnerable: true,
+    }; "no message within a blink phase"
+)]
+#[test_case(
+    UpdateInvulnerableCase {
+        remaining: 1.25,
+        advance: 0.1,
+        expected_color: Some(Color::linear_rgb(0.0, 1.0, 0.0)),
+        expected_invulnerable: true,
+    }; "brightens in the next blink phase"
+)]
+#[test_case(
+    UpdateInvulnerableCase {
+        remaining: 0.05,
+        advance: 0.1,
+        expected_color: Some(Color::linear_rgb(0.0, 1.0, 0.0)),
+        expected_invulnerable: false,
+    }; "restores the health color when it runs out"
+)]
+fn test_update_invulnerable(case: UpdateInvulnerableCase) {
+    let mut app = App::new();
+    app.add_message::<rendering::messages::TweenMessage>();
+    app.add_systems(Update, systems::update_invulnerable);
+    app.insert_resource(Time::<()>::default());
+    let entity = app
+        .world_mut()
+        .spawn((
+            components::Health { max: 3, current: 3 },
+            components::HealthColors {
+                stops: vec![
+                    LinearRgba::rgb(1.0, 0.0, 0.0),
+                    LinearRgba::rgb(0.0, 1.0, 0.0),
+                ],
+            },
+            components::InvulnerableOnDamage {
+                duration: 1.5,
+                blink_interval: 0.1,
+            },
+            components::Invulnerable {
+                remaining: case.remaining,
+            },
+        ))
+        .id();
+    if case.remaining < 1.5 {
+        // Let the blink start, as if damage landed earlier
+        app.update();
+        app.world_mut()
+            .resource_mut::<Messages<rendering::messages::TweenMessage>>()
+            .clear();
+    }
+
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(std::time::Duration::from_secs_f32(case.advance));
+    app.update();
+
+    let expected: Vec<rendering::messages::TweenMessage> = case
+        .expected_color
+        .map(|color| rendering::messages::TweenMessage {
+            entity,
+            target: rendering::components::TweenTarget::BaseColor(color.to_linear()),
+            duration: 0.0,
+            easing: EaseFunction::Linear,
+            repeat: rendering::components::TweenRepeat::Once,
+        })
+        .into_iter()
+        .collect();
+    test_utils::assertions::assert_messages(&app, &expected);
+    assert_eq!(
+        app.world()
+            .get::<components::Invulnerable>(entity)
+            .is_some(),
+        case.expected_invulnerable
+    );
+}
+
+struct LayeredDamageCase {
+    armor: Option<u8>,
+    shield: Option<u8>,
+    delta: i16,
+    expected_shield: Option<u8>,
+    expected_health: u8,
+}
+
+#[test_case(
+    LayeredDamageCase {
+        armor: Some(1),
+        shield: None,
+        delta: -3,
+        expected_shield: None,
+        expected_health: 8,
+    }; "armor reduces damage by a flat amount"
+)]
+#[test_case(
+    LayeredDamageCase {
+        armor: Some(2),
+        shield: None,
+        delta: -1,
+        expected_shield: None,
+        expected_health: 10,
+    }; "armor can absorb small hits entirely"
+)]
+#[test_case(
+    LayeredDamageCase {
+        armor: None,
+        shield: Some(2),
+        delta: -3,
+        expected_shield: Some(0),
+        expected_health: 9,
+    }; "shield absorbs before health"
+)]
+#[test_case(
+    LayeredDamageCase {
+        armor: Some(1),
+        shield: Some(3),
+        delta: -3,
+        expected_shield: Some(1),
+        expected_health: 10,
+    }; "armor applies before shield"
+)]
+#[test_case(
+    LayeredDamageCase {
+        armor: Some(1),
+        shield: Some(1),
+        delta: 2,
+        expected_shield: Some(1),
+        expected_health: 10,
+    }; "healing skips armor and shield"
+)]
+fn test_health_change_through_armor_and_shield(case: LayeredDamageCase) {
+    let mut app = create_health_change_app();
+    let starting_health = if case.delta > 0 { 8 } else { 10 };
+    let entity = app
+        .world_mut()
+        .spawn(components::Health {
+            max: 10,
+            current: starting_health,
+        })
+        .id();
+    if let Some(armor) = case.armor {
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(components::Armor(armor));
+    }
+    if let Some(shield) = case.shield {
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(components::Shield::new(shield, 2.0, 1.0));
+    }
+
+    app.world_mut().write_message(messages::HealChangedMessage {
+        entity,
+        delta: case.delta,
+        source: None,
+        kind: messages::DamageKind::Impact,
+    });
+    app.update();
+
+    let health = app.world().get::<components::Health>(entity).unwrap();
+    assert_eq!(health.current, case.expected_health);
+    let shield = app
+        .world()
+        .get::<components::Shield>(entity)
+        .map(|shield| shield.current);
+    assert_eq!(shield, case.expected_shield);
+}
+
+struct RechargeShieldCase {
+    current: u8,
+    since_damage: f32,
+    recharge_progress: f32,
+    advance: f32,
+    expected_current: u8,
+}
+
+#[test_case(
+    RechargeShieldCase {
+        current: 0,
+        since_damage: 0.0,
+        recharge_progress: 0.0,
+        advance: 1.0,
+        expected_current: 0,
+    }; "waits for the recharge delay"
+)]
+#[test_case(
+    RechargeShieldCase {
+        current: 0,
+        since_damage: 2.0,
+        recharge_progress: 0.0,
+        advance: 1.0,
+        expected_current: 2,
+    }; "recharges at its rate after a quiet period"
+)]
+#[test_case(
+    RechargeShieldCase {
+        current: 2,
+        since_damage: 2.0,
+        recharge_progress: 0.5,
+        advance: 1.0,
+        expected_current: 3,
+    }; "stops at max"
+)]
+fn test_recharge_shields(case: RechargeShieldCase) {
+    let mut app = App::new();
+    app.add_systems(Update, systems::recharge_shields);
+    let mut time: Time = Time::default();
+    time.advance_by(std::time::Duration::from_secs_f32(case.advance));
+    app.insert_resource(time);
+    let entity = app
+        .world_mut()
+        .spawn(components::Shield {
+            current: case.current,
+            since_damage: case.since_damage,
+            recharge_progress: case.recharge_progress,
+            ..components::Shield::new(3, 2.0, 2.0)
+        })
+        .id();
+
+    app.update();
+
+    let shield = app.world().get::<components::Shield>(entity).unwrap();
+    assert_eq!(shield.current, case.expected_current);
+}
+
+#[test]
+fn test_update_shield_color_scales_emissive_with_remaining_shield() {
+    let mut app = App::new();
+    app.add_message::<rendering::messages::MaterialColorsChangedMessage>();
+    app.add_systems(Update, systems::update_shield_color);
+    let entity = app
+        .world_mut()
+        .spawn((
+            components::Shield {
+                current: 1,
+                ..components::Shield::new(4, 2.0, 1.0)
+            },
+            components::ShieldColor(LinearRgba::rgb(0.0, 1.0, 1.0)),
+        ))
+        .id();
+
+    app.update();
+
+    test_utils::assertions::assert_messages(
+        &app,
+        &[rendering::messages::MaterialColorsChangedMessage {
+            entity,
+            base_color: None,
+            emissive: Some(LinearRgba::rgb(0.0, 0.25, 0.25)),
+        }],
+    );
+}
+
+fn create_over_time_app() -> App {
+    let mut app = App::new();
+    app.add_message::<messages::HealChangedMessage>()
+        .add_message::<messages::ApplyOverTimeMessage>()
+        .add_systems(
+            Update,
+            (
+                systems::apply_over_time,
+                systems::tick_regeneration,
+                systems::tick_damage_over_time,
+            )
+                .chain(),
+        );
+    app.insert_resource(Time::<()>::default());
+    app
+}
+
+fn advance(app: &mut App, seconds: f32) {
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(std::time::Duration::from_secs_f32(seconds));
+    app.update();
+}
+
+struct TickOverTimeCase {
+    kind: messages::OverTimeKind,
+    duration: Option<f32>,
+    steps: Vec<f32>,
+    expected_deltas: Vec<i16>,
+    expected_running: bool,
+}
+
+#[test_case(
+    TickOverTimeCase {
+        kind: messages::OverTimeKind::Regeneration,
+        duration: None,
+        steps: vec![0.25, 0.25],
+        expected_deltas: vec![],
+        expected_running: true,
+    }; "nothing before the first interval"
+)]
+#[test_case(
+    TickOverTimeCase {
+        kind: messages::OverTimeKind::Regeneration,
+        duration: None,
+        steps: vec![0.25, 0.5, 0.75],
+        expected_deltas: vec![2],
+        expected_running: true,
+    }; "regeneration ticks every interval"
+)]
+#[test_case(
+    TickOverTimeCase {
+        kind: messages::OverTimeKind::Damage,
+        duration: None,
+        steps: vec![1.5],
+        expected_deltas: vec![-2, -2],
+        expected_running: true,
+    }; "long frames catch up on missed ticks"
+)]
+#[test_case(
+    TickOverTimeCase {
+        kind: messages::OverTimeKind::Damage,
+        duration: Some(1.5),
+        steps: vec![0.75, 1.0],
+        expected_deltas: vec![-2],
+        expected_running: false,
+    }; "ticks up to the end of the duration then is removed"
+)]
+#[test_case(
+    TickOverTimeCase {
+        kind: messages::OverTimeKind::Damage,
+        duration: Some(1.5),
+        steps: vec![0.75, 1.0, 0.75],
+        expected_deltas: vec![],
+        expected_running: false,
+    }; "no ticks once the duration has run out"
+)]
+fn test_tick_over_time(case: TickOverTimeCase) {
+    let mut app = create_over_time_app();
+    let entity = app.world_mut().spawn_empty().id();
+    let source = app.world_mut().spawn_empty().id();
+    let effect = components::OverTime {
+        source: Some(source),
+        ..components::OverTime::new(2, 0.75, case.duration, components::Stacking::Refresh)
+    };
+    app.world_mut()
+        .write_message(messages::ApplyOverTimeMessage {
+            entity,
+            kind: case.kind,
+            effect,
+        });
+    app.update();
+
+    let (last, steps) = case.steps.split_last().unwrap();
+    for step in steps {
+        advance(&mut app, *step);
+    }
+    app.world_mut()
+        .resource_mut::<Messages<messages::HealChangedMessage>>()
+        .clear();
+    advance(&mut app, *last);
+
+    let expected: Vec<messages::HealChangedMessage> = case
+        .expected_deltas
+        .iter()
+        .map(|&delta| messages::HealChangedMessage {
+            entity,
+            delta,
+            source: Some(source),
+            kind: messages::DamageKind::OverTime,
         })
         .collect();
+    test_utils::assertions::assert_messages(&app, &expected);
+    let running = match case.kind {
+        messages::OverTimeKind::Regeneration => app
+            .world()
+            .get::<components::Regeneration>(entity)
+            .is_some(),
+        messages::OverTimeKind::Damage => app
+            .world()
+            .get::<components::DamageOverTime>(entity)
+            .is_some(),
+    };
+    assert_eq!(running, case.expected_running);
+}
+
+struct StackOverTimeCase {
+    stacking: components::Stacking,
+    expected_duration: Option<f32>,
+    expected_elapsed: f32,
+    expected_per_tick: i16,
+}
+
+#[test_case(
+    StackOverTimeCase {
+        stacking: components::Stacking::Refresh,
+        expected_duration: Some(2.0),
+        expected_elapsed: 0.0,
+        expected_per_tick: 3,
+    }; "refresh restarts with the stronger amount"
+)]
+#[test_case(
+    StackOverTimeCase {
+        stacking: components::Stacking::Intensify { max_stacks: 2 },
+        expected_duration: Some(2.0),
+        expected_elapsed: 0.0,
+        expected_per_tick: 2,
+    }; "intensify adds a stack and restarts"
+)]
+fn test_apply_over_time_stacks_onto_running_effect(case: StackOverTimeCase) {
+    let mut app = create_over_time_app();
+    let entity = app
+        .world_mut()
+        .spawn(components::Regeneration(components::OverTime {
+            elapsed: 0.5,
+            ..components::OverTime::new(1, 1.0, Some(2.0), case.stacking)
+        }))
+        .id();
+
+    app.world_mut()
+        .write_message(messages::ApplyOverTimeMessage {
+            entity,
+            kind: messages::OverTimeKind::Regeneration,
+            effect: components::OverTime::new(3, 1.0, Some(2.0), case.stacking),
+        });
+    app.update();
+
+    let regeneration = app.world().get::<components::Regeneration>(entity).unwrap();
+    assert_eq!(regeneration.0.duration, case.expected_duration);
+    assert_eq!(regeneration.0.elapsed, case.expected_elapsed);
+    assert_eq!(regeneration.0.per_tick(), case.expected_per_tick);
+}
+
+#[test]
+fn test_intensify_is_capped_at_max_stacks() {
+    let mut effect = components::OverTime::new(
+        1,
+        1.0,
+        Some(2.0),
+        components::Stacking::Intensify { max_stacks: 2 },
+    );
+    let incoming = effect;
+
+    effect.stack(&incoming);
+    effect.stack(&incoming);
+
+    assert_eq!(effect.stacks, 2);
+}
+
+#[test]
+fn test_over_time_without_positive_interval_never_ticks() {
+    let mut effect = components::OverTime::new(1, 0.0, Some(2.0), components::Stacking::Refresh);
+
+    assert_eq!(effect.advance(1.0), (0, false));
+    assert_eq!(effect.advance(1.0), (0, true));
+}
+
+#[test]
+fn test_handle_collision_applies_over_time() {
+    let mut app = App::new();
+    app.add_message::<messages::HealChangedMessage>();
+    app.add_message::<messages::ApplyOverTimeMessage>();
+    app.add_message::<physics::messages::CollisionMessage>();
+    app.add_systems(Update, systems::handle_collision);
+
+    let effect = components::OverTime::new(
+        1,
+        1.0,
+        Some(1.0),
+        components::Stacking::Intensify { max_stacks: 2 },
+    );
+    let ball = app.world_mut().spawn_empty().id();
+    let brick = app
+        .world_mut()
+        .spawn((
+            components::Health { current: 3, max: 3 },
+            components::OverTimeOnCollision {
+                kind: messages::OverTimeKind::Damage,
+                effect,
+                affected: components::Affects::SelfOnly,
+            },
+        ))
+        .id();
+
+    app.world_mut()
+        .write_message(physics::messages::CollisionMessage {
+            a: ball,
+            b: brick,
+            normal: Vec3::ZERO,
+            contact_point: Vec3::ZERO,
+            penetration: 0.0,
+        });
+    app.update();
+
+    test_utils::assertions::assert_messages(
+        &app,
+        &[messages::ApplyOverTimeMessage {
+            entity: brick,
+            kind: messages::OverTimeKind::Damage,
+            effect: components::OverTime {
+                source: Some(ball),
+                ..effect
+            },
+        }],
+    );
+}
+
+#[test_case(true, 3; "blocked while invulnerable")]
+#[test_case(false, 2; "lands without granting invulnerability")]
+fn test_damage_over_time_and_invulnerability(invulnerable: bool, expected_current: u8) {
+    let mut app = create_health_change_app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            components::Health { max: 3, current: 3 },
+            components::InvulnerableOnDamage {
+                duration: 1.5,
+                blink_interval: 0.1,
+            },
+        ))
+        .id();
+    if invulnerable {
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(components::Invulnerable { remaining: 1.0 });
+    }
+
+    app.world_mut().write_message(messages::HealChangedMessage {
+        entity,
+        delta: -1,
+        source: None,
+        kind: messages::DamageKind::OverTime,
+    });
+    app.update();
+
+    let health = app.world().get::<components::Health>(entity).unwrap();
+    assert_eq!(health.current, expected_current);
+    assert_eq!(
+        app.world()
+            .get::<components::Invulnerable>(entity)
+            .is_some(),
+        invulnerable
+    );
+}
+
+#[test]
+fn test_regeneration_ticks_update_health_color() {
+    let mut app = create_over_time_app();
+    app.add_message::<messages::DeathMessage>()
+        .add_message::<rendering::messages::TweenMessage>()
+        .add_systems(
+            Update,
+            (systems::handle_health_changed, systems::update_health_color)
+                .chain()
+                .after(systems::tick_damage_over_time),
+        );
+    let entity = app
+        .world_mut()
+        .spawn((
+            components::Health { max: 3, current: 1 },
+            components::HealthColors {
+                stops: vec![
+                    LinearRgba::rgb(1.0, 0.0, 0.0),
+                    LinearRgba::rgb(0.0, 1.0, 0.0),
+                ],
+            },
+            components::Regeneration(components::OverTime::new(
+                1,
+                0.5,
+                None,
+                components::Stacking::Refresh,
+            )),
+        ))
+        .id();
 
+    advance(&mut app, 0.5);
+
+    assert_eq!(
+        app.world()
+            .get::<components::Health>(entity)
+            .unwrap()
+            .current,
+        2
+    );
+    test_utils::assertions::assert_messages(
+        &app,
+        &[health_color_fade(entity, Color::linear_rgb(0.5, 0.5, 0.0))],
+    );
+}
+
+#[test_case(5, 5, Color::linear_rgb(0.0, 1.0, 0.0); "last stop at full health")]
+#[test_case(5, 4, Color::linear_rgb(0.5, 1.0, 0.0); "between the upper stops")]
+#[test_case(5, 3, Color::linear_rgb(1.0, 1.0, 0.0); "middle stop halfway")]
+#[test_case(5, 2, Color::linear_rgb(1.0, 0.5, 0.0); "between the lower stops")]
+#[test_case(5, 1, Color::linear_rgb(1.0, 0.0, 0.0); "first stop at 1 hp")]
+#[test_case(1, 1, Color::linear_rgb(0.0, 1.0, 0.0); "last stop with a single hit point")]
+fn test_health_colors_with_multiple_stops(max: u8, current: u8, expected: Color) {
+    let health_colors = components::HealthColors {
+        stops: vec![
+            LinearRgba::rgb(1.0, 0.0, 0.0),
+            LinearRgba::rgb(1.0, 1.0, 0.0),
+            LinearRgba::rgb(0.0, 1.0, 0.0),
+        ],
+    };
+
+    let color = health_colors.color_for(&components::Health { max, current });
+
+    assert_eq!(color, expected);
+}
+
+enum Pulse {
+    Start,
+    Stop,
+}
+
+struct PulseLowHealthCase {
+    current: u8,
+    shield: Option<u8>,
+    active: bool,
+    expected_pulse: Option<Pulse>,
+}
+
+#[test_case(
+    PulseLowHealthCase {
+        current: 1,
+        shield: None,
+        active: false,
+        expected_pulse: Some(Pulse::Start),
+    }; "pulses at low health"
+)]
+#[test_case(
+    PulseLowHealthCase {
+        current: 1,
+        shield: None,
+        active: true,
+        expected_pulse: None,
+    }; "running pulse keeps going"
+)]
+#[test_case(
+    PulseLowHealthCase {
+        current: 3,
+        shield: None,
+        active: false,
+        expected_pulse: None,
+    }; "nothing above the threshold"
+)]
+#[test_case(
+    PulseLowHealthCase {
+        current: 3,
+        shield: None,
+        active: true,
+        expected_pulse: Some(Pulse::Stop),
+    }; "fades out once healed"
+)]
+#[test_case(
+    PulseLowHealthCase {
+        current: 1,
+        shield: Some(1),
+        active: true,
+        expected_pulse: Some(Pulse::Stop),
+    }; "stops while a shield is up"
+)]
+#[test_case(
+    PulseLowHealthCase {
+        current: 1,
+        shield: Some(0),
+        active: false,
+        expected_pulse: Some(Pulse::Start),
+    }; "pulses once the shield is down"
+)]
+fn test_pulse_low_health(case: PulseLowHealthCase) {
+    let mut app = App::new();
+    app.add_message::<rendering::messages::TweenMessage>();
+    app.add_systems(Update, systems::pulse_low_health);
+    let color = LinearRgba::rgb(1.0, 0.0, 0.0);
+    let entity = app
+        .world_mut()
+        .spawn((
+            components::Health {
+                max: 3,
+                current: case.current,
+            },
+            components::LowHealthPulse {
+                active: case.active,
+                ..components::LowHealthPulse::new(1, color, 2.0)
+            },
+        ))
+        .id();
+    if let Some(current) = case.shield {
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(components::Shield {
+                current,
+                ..components::Shield::new(1, 3.0, 1.0)
+            });
+    }
+
+    app.update();
+
+    let expected: Vec<rendering::messages::TweenMessage> = case
+        .expected_pulse
+        .map(|pulse| match pulse {
+            // Half a pulse from dark to full strength and back
+            Pulse::Start => rendering::messages::TweenMessage {
+                entity,
+                target: rendering::components::TweenTarget::Emissive(color),
+                duration: 0.25,
+                easing: EaseFunction::SineInOut,
+                repeat: rendering::components::TweenRepeat::PingPong,
+            },
+            Pulse::Stop => rendering::messages::TweenMessage {
+                entity,
+                target: rendering::components::TweenTarget::Emissive(LinearRgba::BLACK),
+                duration: 0.15,
+                easing: EaseFunction::SineInOut,
+                repeat: rendering::components::TweenRepeat::Once,
+            },
+        })
+        .into_iter()
+        .collect();
     test_utils::assertions::assert_messages(&app, &expected);
 }
+
+#[test_case(3, false; "undamaged tier at full health")]
+#[test_case(2, false; "undamaged tier until the next tier")]
+#[test_case(1, true; "damaged tier at low health")]
+fn test_update_damage_tier(current: u8, damaged: bool) {
+    let mut app = App::new();
+    app.init_resource::<Assets<Mesh>>()
+        .init_resource::<Assets<StandardMaterial>>()
+        .init_resource::<Assets<Image>>()
+        .init_resource::<rendering::resources::MaterialCache>()
+        .add_systems(Update, systems::update_damage_tier);
+    let meshes = [
+        app.world_mut()
+            .resource_mut::<Assets<Mesh>>()
+            .add(Cuboid::default()),
+        app.world_mut()
+            .resource_mut::<Assets<Mesh>>()
+            .add(Sphere::default()),
+    ];
+    let cracks = app
+        .world_mut()
+        .resource_mut::<Assets<Image>>()
+        .add(Image::default());
+    let material = app
+        .world_mut()
+        .resource_mut::<Assets<StandardMaterial>>()
+        .add(StandardMaterial::default());
+    let entity = app
+        .world_mut()
+        .spawn((
+            components::Health { max: 3, current },
+            components::DamageTiers::new(vec![
+                components::DamageTier {
+                    at: 3,
+                    mesh: meshes[0].clone(),
+                    texture: None,
+                },
+                components::DamageTier {
+                    at: 1,
+                    mesh: meshes[1].clone(),
+                    texture: Some(cracks.clone()),
+                },
+            ]),
+            Mesh3d(meshes[0].clone()),
+            MeshMaterial3d(material.clone()),
+        ))
+        .id();
+
+    app.update();
+
+    let expected_mesh = if damaged { &meshes[1] } else { &meshes[0] };
+    assert_eq!(&app.world().get::<Mesh3d>(entity).unwrap().0, expected_mesh);
+    let material = app
+        .world()
+        .get::<MeshMaterial3d<StandardMaterial>>(entity)
+        .unwrap();
+    let materials = app.world().resource::<Assets<StandardMaterial>>();
+    assert_eq!(
+        materials.get(&material.0).unwrap().base_color_texture,
+        damaged.then_some(cracks)
+    );
+}
+
+#[test]
+fn test_update_damage_tier_restores_undamaged_look_when_healed() {
+    let mut app = App::new();
+    app.init_resource::<Assets<Mesh>>()
+        .init_resource::<Assets<StandardMaterial>>()
+        .init_resource::<rendering::resources::MaterialCache>()
+        .add_systems(Update, systems::update_damage_tier);
+    let undamaged = app
+        .world_mut()
+        .resource_mut::<Assets<Mesh>>()
+        .add(Cuboid::default());
+    let damaged = app
+        .world_mut()
+        .resource_mut::<Assets<Mesh>>()
+        .add(Sphere::default());
+    let entity = app
+        .world_mut()
+        .spawn((
+            components::Health { max: 3, current: 1 },
+            components::DamageTiers::new(vec![
+                components::DamageTier {
+                    at: 3,
+                    mesh: undamaged.clone(),
+                    texture: None,
+                },
+                components::DamageTier {
+                    at: 1,
+                    mesh: damaged.clone(),
+                    texture: None,
+                },
+            ]),
+            Mesh3d(undamaged.clone()),
+        ))
+        .id();
+    app.update();
+    assert_eq!(app.world().get::<Mesh3d>(entity).unwrap().0, damaged);
+
+    app.world_mut()
+        .get_mut::<components::Health>(entity)
+        .unwrap()
+        .current = 3;
+    app.update();
+
+    assert_eq!(app.world().get::<Mesh3d>(entity).unwrap().0, undamaged);
+}
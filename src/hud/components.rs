@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct HealthPips;
+
+/// One pip per point of the player's max health, `0` is the left most pip.
+#[derive(Component)]
+pub struct HealthPip(pub u8);
+
+#[derive(Component)]
+pub struct ScoreText;
+
+#[derive(Component)]
+pub struct BricksRemainingText;
+
+#[derive(Component)]
+pub struct BallSpeedText;
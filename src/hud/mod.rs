@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+use crate::{gameplay, health, states};
+
+#[cfg(test)]
+mod tests;
+
+pub mod components;
+pub mod systems;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(
+        OnEnter(states::GameState::Gameplay),
+        systems::hud_setup.after(gameplay::GameplaySet::Initialize),
+    )
+    .add_systems(
+        Update,
+        (
+            systems::update_health_pips.after(health::systems::handle_health_changed),
+            // Bricks are despawned by `handle_death`, count them before that happens
+            systems::update_bricks_remaining.before(health::systems::handle_death),
+            systems::update_score.run_if(resource_changed::<gameplay::level::resources::Run>),
+            systems::update_ball_speed,
+        )
+            .run_if(in_state(states::GameState::Gameplay)),
+    );
+}
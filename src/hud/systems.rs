@@ -0,0 +1,196 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{ball, brick, level, player};
+use crate::main_menu::systems::TEXT_COLOR;
+use crate::{health, hud, physics, states};
+
+pub const FULL_PIP_COLOR: Color = Color::srgb(0.5, 0.7, 1.0);
+pub const EMPTY_PIP_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+
+pub fn hud_setup(
+    mut commands: Commands,
+    player_query: Query<&health::components::Health, With<player::components::Player>>,
+    brick_query: Query<(), With<brick::components::Brick>>,
+    campaign: Res<level::resources::Campaign>,
+    run: Res<level::resources::Run>,
+) {
+    let text_font = TextFont {
+        font_size: 24.0,
+        ..default()
+    };
+    let (max_health, current_health) = player_query
+        .iter()
+        .next()
+        .map_or((0, 0), |health| (health.max, health.current));
+
+    commands
+        .spawn((
+            Name::new("HUD"),
+            DespawnOnExit(states::GameState::Gameplay),
+            Node {
+                width: percent(100),
+                padding: UiRect::all(px(12)),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Start,
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    hud::components::HealthPips,
+                    Node {
+                        column_gap: px(6),
+                        ..default()
+                    },
+                ))
+                .with_children(|parent| {
+                    for index in 0..max_health {
+                        parent.spawn((
+                            hud::components::HealthPip(index),
+                            Node {
+                                width: px(18),
+                                height: px(18),
+                                ..default()
+                            },
+                            BackgroundColor(pip_color(index, current_health)),
+                        ));
+                    }
+                });
+            parent.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                children![
+                    (
+                        Text::new(campaign.current_level().name.clone()),
+                        text_font.clone(),
+                        TextColor(TEXT_COLOR),
+                    ),
+                    (
+                        hud::components::ScoreText,
+                        Text::new(format_score(run.score)),
+                        text_font.clone(),
+                        TextColor(TEXT_COLOR),
+                    ),
+                ],
+            ));
+            parent.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::End,
+                    ..default()
+                },
+                children![
+                    (
+                        hud::components::BricksRemainingText,
+                        Text::new(format_bricks_remaining(brick_query.iter().count())),
+                        text_font.clone(),
+                        TextColor(TEXT_COLOR),
+                    ),
+                    (
+                        hud::components::BallSpeedText,
+                        Text::new(format_ball_speed(0.0)),
+                        text_font,
+                        TextColor(TEXT_COLOR),
+                    ),
+                ],
+            ));
+        });
+}
+
+pub fn update_health_pips(
+    mut health_changed_messages: MessageReader<health::messages::HealChangedMessage>,
+    mut death_messages: MessageReader<health::messages::DeathMessage>,
+    player_query: Query<&health::components::Health, With<player::components::Player>>,
+    mut pip_query: Query<(&hud::components::HealthPip, &mut BackgroundColor)>,
+) {
+    let player_health_changed = health_changed_messages
+        .read()
+        .any(|message| player_query.contains(message.entity));
+    let player_died = death_messages
+        .read()
+        .any(|message| player_query.contains(message.entity));
+    if !player_health_changed && !player_died {
+        return;
+    }
+
+    let current = if player_died {
+        0
+    } else {
+        player_query
+            .iter()
+            .next()
+            .map_or(0, |health| health.current)
+    };
+    for (pip, mut background_color) in &mut pip_query {
+        *background_color = BackgroundColor(pip_color(pip.0, current));
+    }
+}
+
+pub fn update_bricks_remaining(
+    mut death_messages: MessageReader<health::messages::DeathMessage>,
+    brick_query: Query<
+        (),
+        (
+            With<brick::components::Brick>,
+            Without<health::components::Dying>,
+        ),
+    >,
+    mut text: Single<&mut Text, With<hud::components::BricksRemainingText>>,
+) {
+    let destroyed = death_messages
+        .read()
+        .filter(|message| brick_query.contains(message.entity))
+        .count();
+    if destroyed == 0 {
+        return;
+    }
+
+    let remaining = brick_query.iter().count().saturating_sub(destroyed);
+    text.0 = format_bricks_remaining(remaining);
+}
+
+pub fn update_score(
+    run: Res<level::resources::Run>,
+    mut text: Single<&mut Text, With<hud::components::ScoreText>>,
+) {
+    text.0 = format_score(run.score);
+}
+
+pub fn update_ball_speed(
+    ball_query: Query<
+        &physics::components::Velocity,
+        (
+            With<ball::components::BallModifiers>,
+            Changed<physics::components::Velocity>,
+        ),
+    >,
+    mut text: Single<&mut Text, With<hud::components::BallSpeedText>>,
+) {
+    if let Some(velocity) = ball_query.iter().next() {
+        text.0 = format_ball_speed(velocity.0.z.abs());
+    }
+}
+
+fn pip_color(index: u8, current_health: u8) -> Color {
+    if index < current_health {
+        FULL_PIP_COLOR
+    } else {
+        EMPTY_PIP_COLOR
+    }
+}
+
+pub fn format_score(score: u32) -> String {
+    format!("Score: {score}")
+}
+
+pub fn format_bricks_remaining(remaining: usize) -> String {
+    format!("Bricks: {remaining}")
+}
+
+pub fn format_ball_speed(z_speed: f32) -> String {
+    format!("Speed: {z_speed:.1}")
+}
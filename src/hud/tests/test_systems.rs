@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+use test_case::test_case;
+
+use crate::gameplay::{ball, brick, level, player};
+use crate::hud::{components, systems};
+use crate::{health, physics};
+
+struct UpdateHealthPipsCase {
+    current_health: u8,
+    send_heal_changed: bool,
+    send_death: bool,
+    expected_full_pips: usize,
+}
+
+#[test_case(
+    UpdateHealthPipsCase {
+        current_health: 2,
+        send_heal_changed: true,
+        send_death: false,
+        expected_full_pips: 2,
+    }
+; "health change fills pips up to current health")]
+#[test_case(
+    UpdateHealthPipsCase {
+        current_health: 1,
+        send_heal_changed: true,
+        send_death: true,
+        expected_full_pips: 0,
+    }
+; "death empties every pip")]
+#[test_case(
+    UpdateHealthPipsCase {
+        current_health: 1,
+        send_heal_changed: false,
+        send_death: false,
+        expected_full_pips: 3,
+    }
+; "no messages leaves pips alone")]
+fn test_update_health_pips(case: UpdateHealthPipsCase) {
+    let mut app = App::new();
+    app.add_message::<health::messages::HealChangedMessage>();
+    app.add_message::<health::messages::DeathMessage>();
+    app.add_systems(Update, systems::update_health_pips);
+
+    let player_entity = app
+        .world_mut()
+        .spawn((
+            player::components::Player {},
+            health::components::Health {
+                max: 3,
+                current: case.current_health,
+            },
+        ))
+        .id();
+    for index in 0..3 {
+        app.world_mut().spawn((
+            components::HealthPip(index),
+            BackgroundColor(systems::FULL_PIP_COLOR),
+        ));
+    }
+    if case.send_heal_changed {
+        app.world_mut()
+            .write_message(health::messages::HealChangedMessage {
+                entity: player_entity,
+                delta: -1,
+                source: None,
+                kind: health::messages::DamageKind::Impact,
+            });
+    }
+    if case.send_death {
+        app.world_mut()
+            .write_message(health::messages::DeathMessage {
+                entity: player_entity,
+                source: None,
+                kind: health::messages::DamageKind::Impact,
+            });
+    }
+
+    app.update();
+
+    let mut pip_query = app.world_mut().query::<&BackgroundColor>();
+    let full_pips = pip_query
+        .iter(app.world())
+        .filter(|color| color.0 == systems::FULL_PIP_COLOR)
+        .count();
+    assert_eq!(full_pips, case.expected_full_pips);
+}
+
+fn text_of<T: Component>(app: &mut App) -> String {
+    let mut query = app.world_mut().query_filtered::<&Text, With<T>>();
+    query.single(app.world()).unwrap().0.clone()
+}
+
+#[test]
+fn test_update_bricks_remaining_excludes_destroyed_bricks() {
+    let mut app = App::new();
+    app.add_message::<health::messages::DeathMessage>();
+    app.add_systems(Update, systems::update_bricks_remaining);
+    app.world_mut()
+        .spawn((components::BricksRemainingText, Text::default()));
+
+    let dying_brick = app.world_mut().spawn(brick::components::Brick).id();
+    app.world_mut().spawn(brick::components::Brick);
+    app.world_mut().spawn(brick::components::Brick);
+    app.world_mut()
+        .write_message(health::messages::DeathMessage {
+            entity: dying_brick,
+            source: None,
+            kind: health::messages::DamageKind::Impact,
+        });
+
+    app.update();
+
+    assert_eq!(
+        text_of::<components::BricksRemainingText>(&mut app),
+        systems::format_bricks_remaining(2)
+    );
+}
+
+#[test]
+fn test_update_score_shows_run_score() {
+    let mut app = App::new();
+    app.insert_resource(level::resources::Run {
+        score: 1250,
+        ..default()
+    });
+    app.add_systems(Update, systems::update_score);
+    app.world_mut()
+        .spawn((components::ScoreText, Text::default()));
+
+    app.update();
+
+    assert_eq!(
+        text_of::<components::ScoreText>(&mut app),
+        systems::format_score(1250)
+    );
+}
+
+#[test_case(Vec3::new(1.0, 2.0, 21.5), 21.5; "ball moving away")]
+#[test_case(Vec3::new(0.0, 0.0, -18.0), 18.0; "ball coming back")]
+fn test_update_ball_speed_shows_z_speed(velocity: Vec3, expected_speed: f32) {
+    let mut app = App::new();
+    app.add_systems(Update, systems::update_ball_speed);
+    app.world_mut()
+        .spawn((components::BallSpeedText, Text::default()));
+    app.world_mut().spawn((
+        ball::components::BallModifiers::starting(),
+        physics::components::Velocity(velocity),
+    ));
+
+    app.update();
+
+    assert_eq!(
+        text_of::<components::BallSpeedText>(&mut app),
+        systems::format_ball_speed(expected_speed)
+    );
+}
@@ -1,10 +1,46 @@
 use bevy::prelude::*;
 
+use crate::states;
+
+pub mod resources;
 pub mod systems;
 
 #[cfg(test)]
 mod tests;
 
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub enum InputSet {
+    Actions,
+}
+
 pub fn plugin(app: &mut App) {
-    app.add_systems(Update, systems::grab_mouse);
+    app.init_resource::<resources::PaddleMoveIntent>()
+        .init_resource::<resources::PaddleInputConfig>()
+        .init_resource::<resources::DigitalMoveVelocity>()
+        .init_resource::<resources::PendingRebind>()
+        .init_resource::<resources::DebugToggles>()
+        .add_systems(Startup, systems::load_bindings)
+        .add_systems(
+            Update,
+            systems::save_bindings.run_if(
+                resource_changed::<resources::Bindings>
+                    .and(not(resource_added::<resources::Bindings>)),
+            ),
+        )
+        .add_systems(Update, systems::toggle_debug)
+        .add_systems(
+            Update,
+            systems::grab_mouse.run_if(in_state(states::PauseState::Running)),
+        )
+        .add_systems(
+            Update,
+            (
+                systems::clear_paddle_move_intent,
+                systems::mouse_paddle_move_intent,
+                systems::digital_paddle_move_intent,
+                systems::analog_paddle_move_intent,
+            )
+                .chain()
+                .in_set(InputSet::Actions),
+        );
 }
@@ -0,0 +1,206 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub const BINDINGS_FILE: &str = "bindings.ron";
+
+/// How far the paddle should move this frame in world units, +y is up. Rebuilt every frame
+/// from all input devices before the paddle systems run.
+#[derive(Resource, Default, Debug, PartialEq)]
+pub struct PaddleMoveIntent(pub Vec2);
+
+#[derive(Resource, Clone)]
+pub struct PaddleInputConfig {
+    // World units per pixel of mouse motion
+    pub mouse_sensitivity: f32,
+    // World units per second reached when a key or d-pad direction is held
+    pub digital_max_speed: f32,
+    pub digital_acceleration: f32,
+    // World units per second with the stick fully tilted
+    pub analog_max_speed: f32,
+    // Stick magnitude below which input is ignored
+    pub analog_deadzone: f32,
+}
+
+impl Default for PaddleInputConfig {
+    fn default() -> Self {
+        PaddleInputConfig {
+            mouse_sensitivity: 0.025,
+            digital_max_speed: 18.0,
+            digital_acceleration: 90.0,
+            analog_max_speed: 18.0,
+            analog_deadzone: 0.15,
+        }
+    }
+}
+
+/// Speed built up by held digital inputs, reset as soon as they are released.
+#[derive(Resource, Default, Debug)]
+pub struct DigitalMoveVelocity(pub Vec2);
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Action {
+    Launch,
+    Pause,
+    MoveLeft,
+    MoveRight,
+    MoveUp,
+    MoveDown,
+    ToggleInspector,
+}
+
+impl Action {
+    pub const ALL: [Action; 7] = [
+        Action::Launch,
+        Action::Pause,
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::ToggleInspector,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Action::Launch => "Launch",
+            Action::Pause => "Pause",
+            Action::MoveLeft => "Move Left",
+            Action::MoveRight => "Move Right",
+            Action::MoveUp => "Move Up",
+            Action::MoveDown => "Move Down",
+            Action::ToggleInspector => "Toggle Inspector",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Binding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButton),
+}
+
+impl Binding {
+    pub fn label(&self) -> String {
+        match self {
+            Binding::Key(key) => format!("{key:?}").trim_start_matches("Key").to_string(),
+            Binding::Mouse(button) => format!("Mouse {button:?}"),
+            Binding::Gamepad(button) => format!("Pad {button:?}"),
+        }
+    }
+}
+
+/// Inputs bound to each action, persisted so players can rebind their controls.
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Bindings(pub BTreeMap<Action, Vec<Binding>>);
+
+impl Default for Bindings {
+    fn default() -> Self {
+        Bindings(BTreeMap::from([
+            (
+                Action::Launch,
+                vec![
+                    Binding::Mouse(MouseButton::Left),
+                    Binding::Key(KeyCode::Space),
+                    Binding::Gamepad(GamepadButton::South),
+                ],
+            ),
+            (
+                Action::Pause,
+                vec![
+                    Binding::Key(KeyCode::Escape),
+                    Binding::Gamepad(GamepadButton::Start),
+                ],
+            ),
+            (
+                Action::MoveLeft,
+                vec![
+                    Binding::Key(KeyCode::KeyA),
+                    Binding::Key(KeyCode::ArrowLeft),
+                    Binding::Gamepad(GamepadButton::DPadLeft),
+                ],
+            ),
+            (
+                Action::MoveRight,
+                vec![
+                    Binding::Key(KeyCode::KeyD),
+                    Binding::Key(KeyCode::ArrowRight),
+                    Binding::Gamepad(GamepadButton::DPadRight),
+                ],
+            ),
+            (
+                Action::MoveUp,
+                vec![
+                    Binding::Key(KeyCode::KeyW),
+                    Binding::Key(KeyCode::ArrowUp),
+                    Binding::Gamepad(GamepadButton::DPadUp),
+                ],
+            ),
+            (
+                Action::MoveDown,
+                vec![
+                    Binding::Key(KeyCode::KeyS),
+                    Binding::Key(KeyCode::ArrowDown),
+                    Binding::Gamepad(GamepadButton::DPadDown),
+                ],
+            ),
+            (Action::ToggleInspector, vec![Binding::Key(KeyCode::F1)]),
+        ]))
+    }
+}
+
+impl Bindings {
+    pub fn get(&self, action: Action) -> &[Binding] {
+        self.0.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Fills in default bindings for actions missing from a loaded file, e.g. ones added
+    /// after the file was saved.
+    pub fn with_missing_defaults(mut self) -> Self {
+        for (action, bindings) in Bindings::default().0 {
+            self.0.entry(action).or_insert(bindings);
+        }
+        self
+    }
+
+    /// The other action already using `binding`, if any.
+    pub fn conflict(&self, action: Action, binding: Binding) -> Option<Action> {
+        self.0
+            .iter()
+            .find(|(other, bindings)| **other != action && bindings.contains(&binding))
+            .map(|(other, _)| *other)
+    }
+
+    /// Replaces the keyboard and mouse bindings of `action` with `binding`, or its gamepad
+    /// bindings when `binding` is a gamepad button, so each device keeps working.
+    pub fn rebind(&mut self, action: Action, binding: Binding) -> Result<(), Action> {
+        if let Some(other) = self.conflict(action, binding) {
+            return Err(other);
+        }
+
+        let is_gamepad = |b: &Binding| matches!(b, Binding::Gamepad(_));
+        let bindings = self.0.entry(action).or_default();
+        bindings.retain(|b| is_gamepad(b) != is_gamepad(&binding));
+        bindings.insert(0, binding);
+        Ok(())
+    }
+}
+
+/// Action waiting for the player to press its new binding on the settings screen.
+#[derive(Resource, Default, Debug)]
+pub struct PendingRebind {
+    pub action: Option<Action>,
+    pub conflict: Option<(Binding, Action)>,
+}
+
+#[derive(Resource, Debug)]
+pub struct DebugToggles {
+    pub inspector: bool,
+}
+
+impl Default for DebugToggles {
+    fn default() -> Self {
+        DebugToggles { inspector: true }
+    }
+}
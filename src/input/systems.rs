@@ -1,17 +1,141 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 
+use crate::input;
+use crate::persistence;
+
+/// Reads the state of actions through the player's current bindings.
+#[derive(SystemParam)]
+pub struct ActionInput<'w, 's> {
+    bindings: Res<'w, input::resources::Bindings>,
+    keys: Res<'w, ButtonInput<KeyCode>>,
+    mouse: Res<'w, ButtonInput<MouseButton>>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+}
+
+impl ActionInput<'_, '_> {
+    pub fn pressed(&self, action: input::resources::Action) -> bool {
+        self.bindings
+            .get(action)
+            .iter()
+            .any(|binding| match binding {
+                input::resources::Binding::Key(key) => self.keys.pressed(*key),
+                input::resources::Binding::Mouse(button) => self.mouse.pressed(*button),
+                input::resources::Binding::Gamepad(button) => {
+                    self.gamepads.iter().any(|g| g.pressed(*button))
+                }
+            })
+    }
+
+    pub fn just_pressed(&self, action: input::resources::Action) -> bool {
+        self.bindings
+            .get(action)
+            .iter()
+            .any(|binding| match binding {
+                input::resources::Binding::Key(key) => self.keys.just_pressed(*key),
+                input::resources::Binding::Mouse(button) => self.mouse.just_pressed(*button),
+                input::resources::Binding::Gamepad(button) => {
+                    self.gamepads.iter().any(|g| g.just_pressed(*button))
+                }
+            })
+    }
+}
+
+pub fn load_bindings(mut commands: Commands) {
+    let bindings = persistence::load::<input::resources::Bindings>(input::resources::BINDINGS_FILE);
+    commands.insert_resource(bindings.with_missing_defaults());
+}
+
+pub fn save_bindings(bindings: Res<input::resources::Bindings>) {
+    persistence::save(input::resources::BINDINGS_FILE, bindings.as_ref());
+}
+
+pub fn toggle_debug(
+    actions: ActionInput,
+    mut debug_toggles: ResMut<input::resources::DebugToggles>,
+) {
+    if actions.just_pressed(input::resources::Action::ToggleInspector) {
+        debug_toggles.inspector = !debug_toggles.inspector;
+    }
+}
+
 pub fn grab_mouse(
     mut cursor_options: Single<&mut bevy::window::CursorOptions>,
-    mouse: Res<ButtonInput<MouseButton>>,
-    key: Res<ButtonInput<KeyCode>>,
+    actions: ActionInput,
 ) {
-    if mouse.just_pressed(MouseButton::Left) {
+    if actions.just_pressed(input::resources::Action::Launch) {
         cursor_options.visible = false;
         cursor_options.grab_mode = bevy::window::CursorGrabMode::Locked;
     }
 
-    if key.just_pressed(KeyCode::Escape) {
+    if actions.just_pressed(input::resources::Action::Pause) {
         cursor_options.visible = true;
         cursor_options.grab_mode = bevy::window::CursorGrabMode::None;
     }
 }
+
+pub fn clear_paddle_move_intent(mut intent: ResMut<input::resources::PaddleMoveIntent>) {
+    intent.0 = Vec2::ZERO;
+}
+
+pub fn mouse_paddle_move_intent(
+    mut mouse_motion_message_reader: MessageReader<bevy::input::mouse::MouseMotion>,
+    config: Res<input::resources::PaddleInputConfig>,
+    mut intent: ResMut<input::resources::PaddleMoveIntent>,
+) {
+    let delta: Vec2 = mouse_motion_message_reader.read().map(|ev| ev.delta).sum();
+    // Screen space y grows downwards
+    intent.0 += Vec2::new(delta.x, -delta.y) * config.mouse_sensitivity;
+}
+
+pub fn digital_paddle_move_intent(
+    time: Res<Time>,
+    actions: ActionInput,
+    config: Res<input::resources::PaddleInputConfig>,
+    mut velocity: ResMut<input::resources::DigitalMoveVelocity>,
+    mut intent: ResMut<input::resources::PaddleMoveIntent>,
+) {
+    let mut direction = Vec2::ZERO;
+    for (action, step) in [
+        (input::resources::Action::MoveLeft, -Vec2::X),
+        (input::resources::Action::MoveRight, Vec2::X),
+        (input::resources::Action::MoveDown, -Vec2::Y),
+        (input::resources::Action::MoveUp, Vec2::Y),
+    ] {
+        if actions.pressed(action) {
+            direction += step;
+        }
+    }
+
+    if direction == Vec2::ZERO {
+        velocity.0 = Vec2::ZERO;
+        return;
+    }
+
+    let delta_secs = time.delta_secs();
+    let target = direction.normalize() * config.digital_max_speed;
+    velocity.0 = velocity
+        .0
+        .move_towards(target, config.digital_acceleration * delta_secs);
+    intent.0 += velocity.0 * delta_secs;
+}
+
+pub fn analog_paddle_move_intent(
+    time: Res<Time>,
+    gamepads: Query<&Gamepad>,
+    config: Res<input::resources::PaddleInputConfig>,
+    mut intent: ResMut<input::resources::PaddleMoveIntent>,
+) {
+    for gamepad in &gamepads {
+        let stick = gamepad.left_stick();
+        let magnitude = stick.length();
+        if magnitude <= config.analog_deadzone {
+            continue;
+        }
+
+        // Rescale so movement starts from zero at the edge of the deadzone
+        let scaled =
+            ((magnitude - config.analog_deadzone) / (1.0 - config.analog_deadzone)).min(1.0);
+        intent.0 += stick / magnitude * scaled * config.analog_max_speed * time.delta_secs();
+    }
+}
@@ -38,9 +38,23 @@ struct GrabMouseCase {
         expected_visible: true
     }
 )]
+#[test_case(
+    GrabMouseCase {
+        cursor_options: bevy::window::CursorOptions {
+            visible: true,
+            grab_mode: bevy::window::CursorGrabMode::None,
+            ..default()
+        },
+        press_mouse: None,
+        press_key: Some(KeyCode::Space),
+        expected_grab_mode: bevy::window::CursorGrabMode::Locked,
+        expected_visible: false
+    }
+)]
 fn test_grab_mouse_alters_cursor_options_given_expected_input(case: GrabMouseCase) {
     let mut app = App::new();
     app.add_systems(Update, input::systems::grab_mouse);
+    app.init_resource::<input::resources::Bindings>();
     let entity = app.world_mut().spawn(case.cursor_options).id();
     let mut keyboard_input = ButtonInput::<KeyCode>::default();
     if let Some(press_key) = case.press_key {
@@ -63,3 +77,246 @@ fn test_grab_mouse_alters_cursor_options_given_expected_input(case: GrabMouseCas
     assert_eq!(updated_cursor_options.grab_mode, case.expected_grab_mode);
     assert_eq!(updated_cursor_options.visible, case.expected_visible);
 }
+
+const SENSITIVITY: f32 = 0.025;
+
+fn intent_app(delta_secs: f32) -> App {
+    let mut app = App::new();
+    app.add_message::<bevy::input::mouse::MouseMotion>();
+    app.init_resource::<input::resources::PaddleMoveIntent>();
+    app.init_resource::<input::resources::DigitalMoveVelocity>();
+    app.insert_resource(input::resources::PaddleInputConfig {
+        mouse_sensitivity: SENSITIVITY,
+        digital_max_speed: 10.0,
+        digital_acceleration: 20.0,
+        analog_max_speed: 10.0,
+        analog_deadzone: 0.2,
+    });
+    app.init_resource::<input::resources::Bindings>();
+    app.init_resource::<ButtonInput<KeyCode>>();
+    app.init_resource::<ButtonInput<MouseButton>>();
+
+    let mut time: Time = Time::default();
+    time.advance_by(std::time::Duration::from_secs_f32(delta_secs));
+    app.insert_resource(time);
+    app
+}
+
+fn intent(app: &App) -> Vec2 {
+    app.world()
+        .resource::<input::resources::PaddleMoveIntent>()
+        .0
+}
+
+#[test_case(vec![], Vec2::ZERO; "no mouse movement")]
+#[test_case(vec![Vec2::new(10.0, 0.0)], Vec2::new(10.0 * SENSITIVITY, 0.0); "positive x movement")]
+#[test_case(vec![Vec2::new(-10.0, 0.0)], Vec2::new(-10.0 * SENSITIVITY, 0.0); "negative x movement")]
+#[test_case(vec![Vec2::new(0.0, 10.0)], Vec2::new(0.0, -10.0 * SENSITIVITY); "positive y inverted")]
+#[test_case(vec![Vec2::new(4.0, 0.0), Vec2::new(6.0, 0.0)], Vec2::new(10.0 * SENSITIVITY, 0.0); "sums motion within a frame")]
+fn test_mouse_paddle_move_intent(motion_deltas: Vec<Vec2>, expected_intent: Vec2) {
+    let mut app = intent_app(0.0);
+    app.add_systems(Update, input::systems::mouse_paddle_move_intent);
+    for delta in motion_deltas {
+        app.world_mut()
+            .write_message(bevy::input::mouse::MouseMotion { delta });
+    }
+
+    app.update();
+
+    assert_eq!(intent(&app), expected_intent);
+}
+
+struct DigitalPaddleMoveIntentCase {
+    pressed: Vec<KeyCode>,
+    starting_velocity: Vec2,
+    expected_velocity: Vec2,
+}
+
+#[test_case(
+    DigitalPaddleMoveIntentCase {
+        pressed: vec![KeyCode::KeyD],
+        starting_velocity: Vec2::ZERO,
+        expected_velocity: Vec2::new(5.0, 0.0),
+    }
+; "accelerates from rest")]
+#[test_case(
+    DigitalPaddleMoveIntentCase {
+        pressed: vec![KeyCode::ArrowUp],
+        starting_velocity: Vec2::new(0.0, 8.0),
+        expected_velocity: Vec2::new(0.0, 10.0),
+    }
+; "caps at max speed")]
+#[test_case(
+    DigitalPaddleMoveIntentCase {
+        pressed: vec![],
+        starting_velocity: Vec2::new(8.0, 0.0),
+        expected_velocity: Vec2::ZERO,
+    }
+; "stops when released")]
+#[test_case(
+    DigitalPaddleMoveIntentCase {
+        pressed: vec![KeyCode::KeyA, KeyCode::KeyD],
+        starting_velocity: Vec2::ZERO,
+        expected_velocity: Vec2::ZERO,
+    }
+; "opposite keys cancel out")]
+fn test_digital_paddle_move_intent(case: DigitalPaddleMoveIntentCase) {
+    let delta_secs = 0.25;
+    let mut app = intent_app(delta_secs);
+    app.add_systems(Update, input::systems::digital_paddle_move_intent);
+    app.insert_resource(input::resources::DigitalMoveVelocity(
+        case.starting_velocity,
+    ));
+    let mut keyboard_input = ButtonInput::<KeyCode>::default();
+    for key in case.pressed {
+        keyboard_input.press(key);
+    }
+    app.insert_resource(keyboard_input);
+
+    app.update();
+
+    let velocity = app
+        .world()
+        .resource::<input::resources::DigitalMoveVelocity>()
+        .0;
+    assert_eq!(velocity, case.expected_velocity);
+    assert_eq!(intent(&app), case.expected_velocity * delta_secs);
+}
+
+#[test_case(Vec2::new(0.1, 0.1), Vec2::ZERO; "inside deadzone is ignored")]
+#[test_case(Vec2::new(1.0, 0.0), Vec2::new(10.0, 0.0); "full tilt reaches max speed")]
+#[test_case(Vec2::new(0.0, -0.6), Vec2::new(0.0, -5.0); "rescaled from the deadzone edge")]
+fn test_analog_paddle_move_intent(stick: Vec2, expected_intent: Vec2) {
+    let mut app = intent_app(1.0);
+    app.add_systems(Update, input::systems::analog_paddle_move_intent);
+    let mut gamepad = Gamepad::default();
+    gamepad.analog_mut().set(GamepadAxis::LeftStickX, stick.x);
+    gamepad.analog_mut().set(GamepadAxis::LeftStickY, stick.y);
+    app.world_mut().spawn(gamepad);
+
+    app.update();
+
+    assert!(
+        (intent(&app) - expected_intent).length() < 1e-5,
+        "expected {:?}, got {:?}",
+        expected_intent,
+        intent(&app)
+    );
+}
+
+#[test]
+fn test_digital_paddle_move_intent_follows_rebound_keys() {
+    let delta_secs = 0.25;
+    let mut app = intent_app(delta_secs);
+    app.add_systems(Update, input::systems::digital_paddle_move_intent);
+    let mut bindings = input::resources::Bindings::default();
+    bindings
+        .rebind(
+            input::resources::Action::MoveRight,
+            input::resources::Binding::Key(KeyCode::KeyL),
+        )
+        .unwrap();
+    app.insert_resource(bindings);
+    let mut keyboard_input = ButtonInput::<KeyCode>::default();
+    keyboard_input.press(KeyCode::KeyD);
+    keyboard_input.press(KeyCode::KeyL);
+    app.insert_resource(keyboard_input);
+
+    app.update();
+
+    // D is no longer bound to anything, L alone moves right
+    let velocity = app
+        .world()
+        .resource::<input::resources::DigitalMoveVelocity>()
+        .0;
+    assert_eq!(velocity, Vec2::new(5.0, 0.0));
+}
+
+struct RebindCase {
+    action: input::resources::Action,
+    binding: input::resources::Binding,
+    expected_result: Result<(), input::resources::Action>,
+    expected_bindings: Vec<input::resources::Binding>,
+}
+
+#[test_case(
+    RebindCase {
+        action: input::resources::Action::Launch,
+        binding: input::resources::Binding::Key(KeyCode::Enter),
+        expected_result: Ok(()),
+        expected_bindings: vec![
+            input::resources::Binding::Key(KeyCode::Enter),
+            input::resources::Binding::Gamepad(GamepadButton::South),
+        ],
+    }
+; "key replaces keyboard and mouse bindings but keeps gamepad")]
+#[test_case(
+    RebindCase {
+        action: input::resources::Action::Pause,
+        binding: input::resources::Binding::Gamepad(GamepadButton::Select),
+        expected_result: Ok(()),
+        expected_bindings: vec![
+            input::resources::Binding::Gamepad(GamepadButton::Select),
+            input::resources::Binding::Key(KeyCode::Escape),
+        ],
+    }
+; "gamepad button replaces only gamepad bindings")]
+#[test_case(
+    RebindCase {
+        action: input::resources::Action::MoveLeft,
+        binding: input::resources::Binding::Key(KeyCode::KeyD),
+        expected_result: Err(input::resources::Action::MoveRight),
+        expected_bindings: vec![
+            input::resources::Binding::Key(KeyCode::KeyA),
+            input::resources::Binding::Key(KeyCode::ArrowLeft),
+            input::resources::Binding::Gamepad(GamepadButton::DPadLeft),
+        ],
+    }
+; "conflicting binding is rejected")]
+fn test_bindings_rebind(case: RebindCase) {
+    let mut bindings = input::resources::Bindings::default();
+
+    let result = bindings.rebind(case.action, case.binding);
+
+    assert_eq!(result, case.expected_result);
+    assert_eq!(bindings.get(case.action), case.expected_bindings.as_slice());
+}
+
+#[test]
+fn test_bindings_with_missing_defaults_keeps_loaded_bindings() {
+    let loaded = input::resources::Bindings(std::collections::BTreeMap::from([(
+        input::resources::Action::Launch,
+        vec![input::resources::Binding::Key(KeyCode::Enter)],
+    )]));
+
+    let bindings = loaded.with_missing_defaults();
+
+    assert_eq!(
+        bindings.get(input::resources::Action::Launch),
+        &[input::resources::Binding::Key(KeyCode::Enter)]
+    );
+    assert_eq!(
+        bindings.get(input::resources::Action::Pause),
+        input::resources::Bindings::default().get(input::resources::Action::Pause)
+    );
+}
+
+#[test]
+fn test_toggle_debug_flips_inspector() {
+    let mut app = App::new();
+    app.init_resource::<input::resources::Bindings>();
+    app.init_resource::<input::resources::DebugToggles>();
+    app.init_resource::<ButtonInput<MouseButton>>();
+    let mut keyboard_input = ButtonInput::<KeyCode>::default();
+    keyboard_input.press(KeyCode::F1);
+    app.insert_resource(keyboard_input);
+    app.add_systems(Update, input::systems::toggle_debug);
+
+    app.update();
+
+    assert!(
+        !app.world()
+            .resource::<input::resources::DebugToggles>()
+            .inspector
+    );
+}
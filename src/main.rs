@@ -1,45 +1,60 @@
 use bevy::prelude::*;
-use bevy::window;
 use bevy_inspector_egui::{bevy_egui, quick};
 
+mod camera;
+mod game_over;
 mod gameplay;
 mod health;
+mod hud;
 mod input;
 mod main_menu;
+mod particles;
+mod pause_menu;
+mod persistence;
 mod physics;
 mod rendering;
 mod scene;
+mod settings;
 mod states;
+mod theme;
 
 #[cfg(test)]
 mod test_utils;
 
 fn main() {
     let mut app = App::new();
-    app.add_plugins(DefaultPlugins.set(WindowPlugin {
-        primary_window: Some(Window {
-            // https://github.com/bevyengine/bevy/issues/3317
-            present_mode: window::PresentMode::Immediate, // 🚫 VSync OFF
-            ..default()
-        }),
-        ..default()
-    }))
-    .add_plugins(bevy_egui::EguiPlugin::default())
-    .add_plugins((
-        states::plugin,
-        scene::plugin,
-        gameplay::plugin,
-        physics::plugin,
-        rendering::plugin,
-        health::plugin,
-        main_menu::plugin,
-        input::plugin,
-    ))
-    .add_systems(Startup, setup_egui_settings);
+    app.add_plugins(DefaultPlugins)
+        .add_plugins(bevy_egui::EguiPlugin::default())
+        .add_plugins((
+            states::plugin,
+            scene::plugin,
+            gameplay::plugin,
+            physics::plugin,
+            rendering::plugin,
+            health::plugin,
+            main_menu::plugin,
+            input::plugin,
+            game_over::plugin,
+            pause_menu::plugin,
+            hud::plugin,
+            settings::plugin,
+            particles::plugin,
+            camera::plugin,
+            theme::plugin,
+        ))
+        .add_systems(Startup, setup_egui_settings);
 
     #[cfg(debug_assertions)]
     {
-        app.add_plugins(quick::WorldInspectorPlugin::default());
+        app.add_plugins(
+            quick::WorldInspectorPlugin::default()
+                .run_if(|toggles: Res<input::resources::DebugToggles>| toggles.inspector),
+        );
+        app.add_systems(
+            bevy_egui::EguiPrimaryContextPass,
+            gameplay::paddle::systems::preview_curve_response
+                .run_if(|toggles: Res<input::resources::DebugToggles>| toggles.inspector),
+        );
     }
 
     app.run();
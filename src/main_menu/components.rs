@@ -1,15 +1,46 @@
 use bevy::prelude::*;
 
+use crate::input;
+use crate::settings;
+
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MenuButtonAction {
     Play,
+    Continue,
+    Settings,
     Quit,
 }
 
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsButtonAction {
+    Back,
+}
+
 #[derive(Component)]
 pub struct SelectedOption;
 
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RebindButton(pub input::resources::Action);
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BindingText(pub input::resources::Action);
+
+#[derive(Component)]
+pub struct RebindStatusText;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdjustSettingButton {
+    pub setting: settings::resources::Setting,
+    pub steps: i8,
+}
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SettingValueText(pub settings::resources::Setting);
+
 pub type MenuInteraction<'a> = (&'a Interaction, &'a MenuButtonAction);
+pub type SettingsInteraction<'a> = (&'a Interaction, &'a SettingsButtonAction);
+pub type RebindInteraction<'a> = (&'a Interaction, &'a RebindButton);
+pub type AdjustSettingInteraction<'a> = (&'a Interaction, &'a AdjustSettingButton);
 pub type MenuButtonInteraction<'a> = (
     &'a Interaction,
     &'a mut BackgroundColor,
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::input;
+use crate::settings;
 use crate::states;
 
 #[cfg(test)]
@@ -12,8 +14,32 @@ pub fn plugin(app: &mut App) {
     app.add_systems(OnEnter(states::GameState::Menu), systems::menu_setup)
         .add_systems(
             Update,
-            (systems::menu_action, systems::button_system)
-                .run_if(in_state(states::GameState::Menu)),
+            (
+                systems::menu_action.run_if(in_state(states::GameState::Menu)),
+                (
+                    systems::settings_menu_action,
+                    systems::capture_rebind,
+                    systems::rebind_button_action,
+                    systems::update_binding_texts.run_if(
+                        resource_changed::<input::resources::Bindings>
+                            .or(resource_changed::<input::resources::PendingRebind>),
+                    ),
+                    systems::adjust_setting_action,
+                    systems::update_setting_texts
+                        .run_if(resource_changed::<settings::resources::Settings>),
+                )
+                    .chain()
+                    .run_if(in_state(states::MenuState::Settings)),
+            ),
         )
-        .add_systems(OnEnter(states::MenuState::Main), systems::menu_ui_setup);
+        // Shared by every menu screen, it only reacts to changed button interactions
+        .add_systems(Update, systems::button_system)
+        .add_systems(OnEnter(states::MenuState::Main), systems::menu_ui_setup)
+        .add_systems(
+            OnEnter(states::MenuState::Settings),
+            (
+                systems::settings_menu_ui_setup,
+                systems::reset_pending_rebind,
+            ),
+        );
 }
@@ -1,8 +1,11 @@
-use bevy::color::palettes::css::CRIMSON;
 use bevy::prelude::*;
 
+use crate::gameplay::level;
+use crate::input;
 use crate::main_menu::components;
+use crate::settings;
 use crate::states;
+use crate::theme;
 
 pub const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
 pub const HOVERED_BUTTON: Color = Color::srgb(0.25, 0.25, 0.25);
@@ -39,6 +42,9 @@ pub fn menu_action(
     mut app_exit_writer: MessageWriter<AppExit>,
     mut menu_state: ResMut<NextState<states::MenuState>>,
     mut game_state: ResMut<NextState<states::GameState>>,
+    mut campaign: ResMut<level::resources::Campaign>,
+    progress: Res<level::resources::CampaignProgress>,
+    mut run: ResMut<level::resources::Run>,
 ) {
     for (interaction, menu_button_action) in &interaction_query {
         if *interaction == Interaction::Pressed {
@@ -47,26 +53,53 @@ pub fn menu_action(
                     app_exit_writer.write(AppExit::Success);
                 }
                 components::MenuButtonAction::Play => {
+                    campaign.current = 0;
+                    *run = level::resources::Run::default();
                     game_state.set(states::GameState::Gameplay);
                     menu_state.set(states::MenuState::Disabled);
                 }
+                components::MenuButtonAction::Continue => {
+                    campaign.current = progress.unlocked.min(campaign.levels.len() - 1);
+                    *run = level::resources::Run::default();
+                    game_state.set(states::GameState::Gameplay);
+                    menu_state.set(states::MenuState::Disabled);
+                }
+                components::MenuButtonAction::Settings => {
+                    menu_state.set(states::MenuState::Settings);
+                }
             }
         }
     }
 }
 
-const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+pub const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
 
-pub fn menu_ui_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
-    // Common style for all buttons on the screen
-    let button_node = Node {
+/// Common style for all menu buttons, shared with the other menu screens
+pub fn button_node() -> Node {
+    Node {
         width: px(300),
         height: px(65),
         margin: UiRect::all(px(20)),
         justify_content: JustifyContent::Center,
         align_items: AlignItems::Center,
         ..default()
-    };
+    }
+}
+
+pub fn button_text_font() -> TextFont {
+    TextFont {
+        font_size: 33.0,
+        ..default()
+    }
+}
+
+pub fn menu_ui_setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    progress: Res<level::resources::CampaignProgress>,
+    theme: Res<theme::resources::Theme>,
+) {
+    let button_node = button_node();
     let button_icon_node = Node {
         width: px(30),
         // This takes the icons out of the flexbox flow, to be positioned exactly
@@ -75,72 +108,430 @@ pub fn menu_ui_setup(mut commands: Commands, asset_server: Res<AssetServer>) {
         left: px(10),
         ..default()
     };
-    let button_text_font = TextFont {
-        font_size: 33.0,
-        ..default()
-    };
+    let button_text_font = button_text_font();
 
     let right_icon = asset_server.load("textures/icons/right.png");
     let exit_icon = asset_server.load("textures/icons/exitRight.png");
 
-    commands.spawn((
-        DespawnOnExit(states::MenuState::Main),
-        Node {
-            width: percent(100),
-            height: percent(100),
-            align_items: AlignItems::Center,
-            justify_content: JustifyContent::Center,
-            ..default()
-        },
-        children![(
+    commands
+        .spawn((
+            DespawnOnExit(states::MenuState::Main),
             Node {
-                flex_direction: FlexDirection::Column,
+                width: percent(100),
+                height: percent(100),
                 align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
                 ..default()
             },
-            BackgroundColor(CRIMSON.into()),
-            children![
-                // Display the game name
-                (
-                    Text::new("Brickochet"),
-                    TextFont {
-                        font_size: 67.0,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
                         ..default()
                     },
-                    TextColor(TEXT_COLOR),
+                    BackgroundColor(theme.menu_background),
+                    theme::components::ThemedBackground,
+                ))
+                .with_children(|parent| {
+                    // Display the game name
+                    parent.spawn((
+                        Text::new("Brickochet"),
+                        TextFont {
+                            font_size: 67.0,
+                            ..default()
+                        },
+                        TextColor(TEXT_COLOR),
+                        Node {
+                            margin: UiRect::all(px(50)),
+                            ..default()
+                        },
+                    ));
+                    // Display a button for each action available from the main menu:
+                    // - continue, only once a later level has been unlocked
+                    // - new game
+                    // - settings
+                    // - quit
+                    if progress.unlocked > 0 {
+                        parent.spawn((
+                            Button,
+                            button_node.clone(),
+                            BackgroundColor(NORMAL_BUTTON),
+                            components::MenuButtonAction::Continue,
+                            children![
+                                (ImageNode::new(right_icon.clone()), button_icon_node.clone()),
+                                (
+                                    Text::new("Continue"),
+                                    button_text_font.clone(),
+                                    TextColor(TEXT_COLOR),
+                                ),
+                            ],
+                        ));
+                    }
+                    parent.spawn((
+                        Button,
+                        button_node.clone(),
+                        BackgroundColor(NORMAL_BUTTON),
+                        components::MenuButtonAction::Play,
+                        children![
+                            (ImageNode::new(right_icon.clone()), button_icon_node.clone()),
+                            (
+                                Text::new("New Game"),
+                                button_text_font.clone(),
+                                TextColor(TEXT_COLOR),
+                            ),
+                        ],
+                    ));
+                    parent.spawn((
+                        Button,
+                        button_node.clone(),
+                        BackgroundColor(NORMAL_BUTTON),
+                        components::MenuButtonAction::Settings,
+                        children![
+                            (ImageNode::new(right_icon), button_icon_node.clone()),
+                            (
+                                Text::new("Settings"),
+                                button_text_font.clone(),
+                                TextColor(TEXT_COLOR),
+                            ),
+                        ],
+                    ));
+                    parent.spawn((
+                        Button,
+                        button_node,
+                        BackgroundColor(NORMAL_BUTTON),
+                        components::MenuButtonAction::Quit,
+                        children![
+                            (ImageNode::new(exit_icon), button_icon_node),
+                            (Text::new("Quit"), button_text_font, TextColor(TEXT_COLOR)),
+                        ],
+                    ));
+                });
+        });
+}
+
+pub fn settings_menu_action(
+    interaction_query: Query<components::SettingsInteraction, components::RecentButtonInteraction>,
+    game_state: Res<State<states::GameState>>,
+    mut menu_state: ResMut<NextState<states::MenuState>>,
+) {
+    for (interaction, action) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match action {
+            components::SettingsButtonAction::Back => {
+                // Settings can be opened from the pause menu as well as the main menu
+                if *game_state.get() == states::GameState::Gameplay {
+                    menu_state.set(states::MenuState::Pause);
+                } else {
+                    menu_state.set(states::MenuState::Main);
+                }
+            }
+        }
+    }
+}
+
+pub fn rebind_button_action(
+    interaction_query: Query<components::RebindInteraction, components::RecentButtonInteraction>,
+    mut pending: ResMut<input::resources::PendingRebind>,
+) {
+    for (interaction, rebind_button) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            pending.action = Some(rebind_button.0);
+            pending.conflict = None;
+        }
+    }
+}
+
+// Runs before the rebind buttons are handled so the click that starts a rebind isn't
+// captured as the new binding. Escape cancels, clicks on menu buttons are left to the buttons.
+pub fn capture_rebind(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
+    button_query: Query<&Interaction, With<Button>>,
+    mut pending: ResMut<input::resources::PendingRebind>,
+    mut bindings: ResMut<input::resources::Bindings>,
+) {
+    let Some(action) = pending.action else {
+        return;
+    };
+
+    if keys.just_pressed(KeyCode::Escape) {
+        *pending = input::resources::PendingRebind::default();
+        return;
+    }
+
+    let over_button = button_query
+        .iter()
+        .any(|interaction| *interaction != Interaction::None);
+    let pressed = keys
+        .get_just_pressed()
+        .next()
+        .map(|key| input::resources::Binding::Key(*key))
+        .or_else(|| {
+            mouse
+                .get_just_pressed()
+                .next()
+                .filter(|_| !over_button)
+                .map(|button| input::resources::Binding::Mouse(*button))
+        })
+        .or_else(|| {
+            gamepads.iter().find_map(|gamepad| {
+                gamepad
+                    .get_just_pressed()
+                    .next()
+                    .map(|button| input::resources::Binding::Gamepad(*button))
+            })
+        });
+    let Some(binding) = pressed else {
+        return;
+    };
+
+    match bindings.rebind(action, binding) {
+        Ok(()) => {
+            pending.action = None;
+            pending.conflict = None;
+        }
+        // Keep waiting so the player can pick a different input
+        Err(other) => pending.conflict = Some((binding, other)),
+    }
+}
+
+pub fn format_bindings(bindings: &[input::resources::Binding]) -> String {
+    bindings
+        .iter()
+        .map(input::resources::Binding::label)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn update_binding_texts(
+    bindings: Res<input::resources::Bindings>,
+    pending: Res<input::resources::PendingRebind>,
+    mut binding_texts: Query<(&mut Text, &components::BindingText)>,
+    mut status_texts: Query<
+        &mut Text,
+        (
+            With<components::RebindStatusText>,
+            Without<components::BindingText>,
+        ),
+    >,
+) {
+    for (mut text, binding_text) in &mut binding_texts {
+        text.0 = if pending.action == Some(binding_text.0) {
+            "Press a key, Esc to cancel".to_string()
+        } else {
+            format_bindings(bindings.get(binding_text.0))
+        };
+    }
+
+    for mut text in &mut status_texts {
+        text.0 = match pending.conflict {
+            Some((binding, other)) => {
+                format!("{} is already bound to {}", binding.label(), other.label())
+            }
+            None => String::new(),
+        };
+    }
+}
+
+pub fn adjust_setting_action(
+    interaction_query: Query<
+        components::AdjustSettingInteraction,
+        components::RecentButtonInteraction,
+    >,
+    mut settings: ResMut<settings::resources::Settings>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            button.setting.adjust(&mut settings, button.steps);
+        }
+    }
+}
+
+pub fn update_setting_texts(
+    settings: Res<settings::resources::Settings>,
+    mut value_texts: Query<(&mut Text, &components::SettingValueText)>,
+) {
+    for (mut text, value_text) in &mut value_texts {
+        text.0 = value_text.0.value_text(&settings);
+    }
+}
+
+// Also marks the resource changed so the binding texts are filled in on entering the screen
+pub fn reset_pending_rebind(mut pending: ResMut<input::resources::PendingRebind>) {
+    *pending = input::resources::PendingRebind::default();
+}
+
+pub fn settings_menu_ui_setup(
+    mut commands: Commands,
+    settings: Res<settings::resources::Settings>,
+    theme: Res<theme::resources::Theme>,
+) {
+    commands
+        .spawn((
+            DespawnOnExit(states::MenuState::Settings),
+            Node {
+                width: percent(100),
+                height: percent(100),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
                     Node {
-                        margin: UiRect::all(px(50)),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
                         ..default()
                     },
-                ),
-                // Display two buttons for each action available from the main menu:
-                // - new game
-                // - quit
-                (
-                    Button,
-                    button_node.clone(),
-                    BackgroundColor(NORMAL_BUTTON),
-                    components::MenuButtonAction::Play,
-                    children![
-                        (ImageNode::new(right_icon), button_icon_node.clone()),
-                        (
-                            Text::new("New Game"),
-                            button_text_font.clone(),
-                            TextColor(TEXT_COLOR),
-                        ),
-                    ]
-                ),
-                (
-                    Button,
-                    button_node,
-                    BackgroundColor(NORMAL_BUTTON),
-                    components::MenuButtonAction::Quit,
-                    children![
-                        (ImageNode::new(exit_icon), button_icon_node),
-                        (Text::new("Quit"), button_text_font, TextColor(TEXT_COLOR),),
-                    ]
-                ),
-            ]
-        )],
-    ));
+                    BackgroundColor(theme.menu_background),
+                    theme::components::ThemedBackground,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Settings"),
+                        TextFont {
+                            font_size: 67.0,
+                            ..default()
+                        },
+                        TextColor(TEXT_COLOR),
+                        Node {
+                            margin: UiRect::all(px(15)),
+                            ..default()
+                        },
+                    ));
+                    for action in input::resources::Action::ALL {
+                        parent.spawn((
+                            Node {
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            children![
+                                (
+                                    Text::new(action.label()),
+                                    TextFont {
+                                        font_size: 24.0,
+                                        ..default()
+                                    },
+                                    TextColor(TEXT_COLOR),
+                                    Node {
+                                        width: px(220),
+                                        margin: UiRect::horizontal(px(20)),
+                                        ..default()
+                                    },
+                                ),
+                                (
+                                    Button,
+                                    Node {
+                                        width: px(360),
+                                        height: px(32),
+                                        margin: UiRect::all(px(3)),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },
+                                    BackgroundColor(NORMAL_BUTTON),
+                                    components::RebindButton(action),
+                                    children![(
+                                        Text::default(),
+                                        components::BindingText(action),
+                                        TextFont {
+                                            font_size: 20.0,
+                                            ..default()
+                                        },
+                                        TextColor(TEXT_COLOR),
+                                    )],
+                                ),
+                            ],
+                        ));
+                    }
+                    for setting in settings::resources::Setting::ALL {
+                        parent.spawn((
+                            Node {
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            children![
+                                (
+                                    Text::new(setting.label()),
+                                    TextFont {
+                                        font_size: 24.0,
+                                        ..default()
+                                    },
+                                    TextColor(TEXT_COLOR),
+                                    Node {
+                                        width: px(220),
+                                        margin: UiRect::horizontal(px(20)),
+                                        ..default()
+                                    },
+                                ),
+                                adjust_setting_button(setting, -1, "-"),
+                                (
+                                    Text::new(setting.value_text(&settings)),
+                                    components::SettingValueText(setting),
+                                    TextFont {
+                                        font_size: 20.0,
+                                        ..default()
+                                    },
+                                    TextColor(TEXT_COLOR),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    Node {
+                                        width: px(240),
+                                        ..default()
+                                    },
+                                ),
+                                adjust_setting_button(setting, 1, "+"),
+                            ],
+                        ));
+                    }
+                    parent.spawn((
+                        Text::default(),
+                        components::RebindStatusText,
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(TEXT_COLOR),
+                        Node {
+                            margin: UiRect::all(px(10)),
+                            ..default()
+                        },
+                    ));
+                    parent.spawn((
+                        Button,
+                        button_node(),
+                        BackgroundColor(NORMAL_BUTTON),
+                        components::SettingsButtonAction::Back,
+                        children![(Text::new("Back"), button_text_font(), TextColor(TEXT_COLOR))],
+                    ));
+                });
+        });
+}
+
+fn adjust_setting_button(
+    setting: settings::resources::Setting,
+    steps: i8,
+    label: &str,
+) -> impl Bundle {
+    (
+        Button,
+        Node {
+            width: px(40),
+            height: px(32),
+            margin: UiRect::all(px(3)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(NORMAL_BUTTON),
+        components::AdjustSettingButton { setting, steps },
+        children![(Text::new(label), button_text_font(), TextColor(TEXT_COLOR))],
+    )
 }
@@ -1,9 +1,13 @@
 use bevy::prelude::*;
 use test_case::test_case;
 
+use crate::gameplay::level;
+use crate::input;
 use crate::main_menu::{components, systems};
+use crate::settings;
 use crate::states;
 use crate::test_utils;
+use crate::theme;
 
 /// A struct representing a single test case for the button system
 #[derive(Debug)]
@@ -62,6 +66,8 @@ struct MenuActionTestCase {
     should_exit: bool,
     expected_game_state: states::GameState,
     expected_menu_state: states::MenuState,
+    unlocked: usize,
+    expected_level: usize,
 }
 
 fn setup_menu_action_test(case: &MenuActionTestCase) -> (App, Entity) {
@@ -73,6 +79,18 @@ fn setup_menu_action_test(case: &MenuActionTestCase) -> (App, Entity) {
         states::plugin,
     ));
     app.add_systems(Update, systems::menu_action);
+    app.insert_resource(level::resources::Campaign {
+        current: 1,
+        ..level::resources::Campaign::standard()
+    });
+    app.insert_resource(level::resources::CampaignProgress {
+        unlocked: case.unlocked,
+    });
+    app.insert_resource(level::resources::Run {
+        score: 500,
+        player_health: Some(1),
+        ..default()
+    });
 
     let entity = app
         .world_mut()
@@ -89,8 +107,32 @@ fn setup_menu_action_test(case: &MenuActionTestCase) -> (App, Entity) {
         should_exit: false,
         expected_game_state: states::GameState::Gameplay,
         expected_menu_state: states::MenuState::Disabled,
+        unlocked: 2,
+        expected_level: 0,
     }
 ; "when play button pressed, moves to gameplay state")]
+#[test_case(
+    MenuActionTestCase {
+        action: components::MenuButtonAction::Continue,
+        interaction: Interaction::Pressed,
+        should_exit: false,
+        expected_game_state: states::GameState::Gameplay,
+        expected_menu_state: states::MenuState::Disabled,
+        unlocked: 2,
+        expected_level: 2,
+    }
+; "when continue button pressed, starts from last unlocked level")]
+#[test_case(
+    MenuActionTestCase {
+        action: components::MenuButtonAction::Settings,
+        interaction: Interaction::Pressed,
+        should_exit: false,
+        expected_game_state: states::GameState::Menu, // unchanged
+        expected_menu_state: states::MenuState::Settings,
+        unlocked: 0,
+        expected_level: 1,
+    }
+; "when settings button pressed, opens the settings screen")]
 #[test_case(
     MenuActionTestCase {
         action: components::MenuButtonAction::Quit,
@@ -98,6 +140,8 @@ fn setup_menu_action_test(case: &MenuActionTestCase) -> (App, Entity) {
         should_exit: true,
         expected_game_state: states::GameState::Menu, // unchanged
         expected_menu_state: states::MenuState::Disabled,    // unchanged
+        unlocked: 0,
+        expected_level: 1,
     }
 ; "when quit button pressed, exits app")]
 #[test_case(
@@ -107,6 +151,8 @@ fn setup_menu_action_test(case: &MenuActionTestCase) -> (App, Entity) {
         should_exit: false,
         expected_game_state: states::GameState::Menu, // unchanged
         expected_menu_state: states::MenuState::Disabled, // unchanged
+        unlocked: 0,
+        expected_level: 1,
     }
 ; "wen play button hovered, no state change but color changes")]
 fn test_menu_action_system(case: MenuActionTestCase) {
@@ -123,4 +169,212 @@ fn test_menu_action_system(case: MenuActionTestCase) {
     assert_eq!(*menu_state, case.expected_menu_state);
     let expected_message_count = if case.should_exit { 1 } else { 0 };
     test_utils::assertions::assert_message_count::<AppExit>(&app, expected_message_count);
+    let campaign = app.world().resource::<level::resources::Campaign>();
+    assert_eq!(campaign.current, case.expected_level);
+}
+
+#[test_case(states::GameState::Menu, states::MenuState::Main; "back from the main menu settings")]
+#[test_case(states::GameState::Gameplay, states::MenuState::Pause; "back from the pause menu settings")]
+fn test_settings_back_returns_to_opening_menu(
+    game_state: states::GameState,
+    expected_menu_state: states::MenuState,
+) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        bevy::state::app::StatesPlugin,
+        states::plugin,
+    ));
+    app.insert_state(game_state);
+    app.insert_state(states::MenuState::Settings);
+    app.add_systems(Update, systems::settings_menu_action);
+    app.world_mut().spawn((
+        Button,
+        components::SettingsButtonAction::Back,
+        Interaction::Pressed,
+    ));
+
+    app.update();
+    app.update();
+
+    let menu_state = app.world().resource::<State<states::MenuState>>();
+    assert_eq!(*menu_state, expected_menu_state);
+}
+
+#[test]
+fn test_controls_can_be_rebound_from_the_main_menu() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        bevy::state::app::StatesPlugin,
+        states::plugin,
+    ));
+    app.insert_state(states::MenuState::Main);
+    app.insert_resource(level::resources::Campaign::standard());
+    app.init_resource::<level::resources::CampaignProgress>();
+    app.init_resource::<level::resources::Run>();
+    app.init_resource::<settings::resources::Settings>();
+    app.init_resource::<theme::resources::Theme>();
+    app.init_resource::<input::resources::PendingRebind>();
+    app.add_systems(Update, systems::menu_action).add_systems(
+        OnEnter(states::MenuState::Settings),
+        systems::settings_menu_ui_setup,
+    );
+    app.world_mut().spawn((
+        Button,
+        components::MenuButtonAction::Settings,
+        Interaction::Pressed,
+    ));
+
+    app.update();
+    app.update();
+
+    let rebind_buttons = app
+        .world_mut()
+        .query::<&components::RebindButton>()
+        .iter(app.world())
+        .count();
+    assert_eq!(rebind_buttons, input::resources::Action::ALL.len());
+}
+
+struct CaptureRebindCase {
+    press_key: KeyCode,
+    expected_pending: Option<input::resources::Action>,
+    expected_conflict: Option<(input::resources::Binding, input::resources::Action)>,
+    expected_bindings: Vec<input::resources::Binding>,
+}
+
+#[test_case(
+    CaptureRebindCase {
+        press_key: KeyCode::KeyJ,
+        expected_pending: None,
+        expected_conflict: None,
+        expected_bindings: vec![
+            input::resources::Binding::Key(KeyCode::KeyJ),
+            input::resources::Binding::Gamepad(GamepadButton::DPadLeft),
+        ],
+    }
+; "free key is bound")]
+#[test_case(
+    CaptureRebindCase {
+        press_key: KeyCode::Space,
+        expected_pending: Some(input::resources::Action::MoveLeft),
+        expected_conflict: Some((
+            input::resources::Binding::Key(KeyCode::Space),
+            input::resources::Action::Launch,
+        )),
+        expected_bindings: vec![
+            input::resources::Binding::Key(KeyCode::KeyA),
+            input::resources::Binding::Key(KeyCode::ArrowLeft),
+            input::resources::Binding::Gamepad(GamepadButton::DPadLeft),
+        ],
+    }
+; "conflicting key keeps waiting")]
+#[test_case(
+    CaptureRebindCase {
+        press_key: KeyCode::Escape,
+        expected_pending: None,
+        expected_conflict: None,
+        expected_bindings: vec![
+            input::resources::Binding::Key(KeyCode::KeyA),
+            input::resources::Binding::Key(KeyCode::ArrowLeft),
+            input::resources::Binding::Gamepad(GamepadButton::DPadLeft),
+        ],
+    }
+; "escape cancels")]
+fn test_capture_rebind(case: CaptureRebindCase) {
+    let mut app = App::new();
+    app.init_resource::<input::resources::Bindings>();
+    app.insert_resource(input::resources::PendingRebind {
+        action: Some(input::resources::Action::MoveLeft),
+        conflict: None,
+    });
+    app.init_resource::<ButtonInput<MouseButton>>();
+    let mut keyboard_input = ButtonInput::<KeyCode>::default();
+    keyboard_input.press(case.press_key);
+    app.insert_resource(keyboard_input);
+    app.add_systems(Update, systems::capture_rebind);
+
+    app.update();
+
+    let pending = app.world().resource::<input::resources::PendingRebind>();
+    assert_eq!(pending.action, case.expected_pending);
+    assert_eq!(pending.conflict, case.expected_conflict);
+    let bindings = app.world().resource::<input::resources::Bindings>();
+    assert_eq!(
+        bindings.get(input::resources::Action::MoveLeft),
+        case.expected_bindings.as_slice()
+    );
+}
+
+#[test_case(Interaction::None, None; "click away from buttons is bound")]
+#[test_case(Interaction::Pressed, Some(input::resources::Action::MoveLeft); "click on a button is ignored")]
+fn test_capture_rebind_mouse(
+    button_interaction: Interaction,
+    expected_pending: Option<input::resources::Action>,
+) {
+    let mut app = App::new();
+    app.init_resource::<input::resources::Bindings>();
+    app.insert_resource(input::resources::PendingRebind {
+        action: Some(input::resources::Action::MoveLeft),
+        conflict: None,
+    });
+    app.init_resource::<ButtonInput<KeyCode>>();
+    let mut mouse_input = ButtonInput::<MouseButton>::default();
+    mouse_input.press(MouseButton::Middle);
+    app.insert_resource(mouse_input);
+    app.world_mut().spawn((
+        Button,
+        components::SettingsButtonAction::Back,
+        button_interaction,
+    ));
+    app.add_systems(Update, systems::capture_rebind);
+
+    app.update();
+
+    let pending = app.world().resource::<input::resources::PendingRebind>();
+    assert_eq!(pending.action, expected_pending);
+    let bound = app
+        .world()
+        .resource::<input::resources::Bindings>()
+        .get(input::resources::Action::MoveLeft)
+        .contains(&input::resources::Binding::Mouse(MouseButton::Middle));
+    assert_eq!(bound, expected_pending.is_none());
+}
+
+#[test]
+fn test_rebind_button_starts_waiting_for_input() {
+    let mut app = App::new();
+    app.init_resource::<input::resources::PendingRebind>();
+    app.add_systems(Update, systems::rebind_button_action);
+    app.world_mut().spawn((
+        Button,
+        components::RebindButton(input::resources::Action::Pause),
+        Interaction::Pressed,
+    ));
+
+    app.update();
+
+    let pending = app.world().resource::<input::resources::PendingRebind>();
+    assert_eq!(pending.action, Some(input::resources::Action::Pause));
+}
+
+#[test]
+fn test_adjust_setting_button_changes_settings() {
+    let mut app = App::new();
+    app.init_resource::<settings::resources::Settings>();
+    app.add_systems(Update, systems::adjust_setting_action);
+    app.world_mut().spawn((
+        Button,
+        components::AdjustSettingButton {
+            setting: settings::resources::Setting::FieldOfView,
+            steps: 1,
+        },
+        Interaction::Pressed,
+    ));
+
+    app.update();
+
+    let settings = app.world().resource::<settings::resources::Settings>();
+    assert_eq!(settings.fov_degrees, 65.0);
 }
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+
+use crate::gameplay::ball;
+use crate::{particles, physics};
+
+/// Pooled particle, inactive ones stay hidden until a burst reuses them.
+#[derive(Component)]
+pub struct Particle {
+    pub velocity: Vec3,
+    pub age: f32,
+    pub lifetime: f32,
+    pub size: f32,
+    pub active: bool,
+}
+
+/// Thrown off wherever a ball hits this entity.
+#[derive(Component)]
+pub struct ImpactParticles(pub particles::messages::ParticleBurst);
+
+/// Thrown off in every direction when this entity dies.
+#[derive(Component)]
+pub struct DeathParticles(pub particles::messages::ParticleBurst);
+
+/// Thrown off by the ball when this paddle gives it the strongest curve it can.
+#[derive(Component)]
+pub struct SuperCurveParticles(pub particles::messages::ParticleBurst);
+
+/// Leaves a particle every `spacing` units moved, longer lived and larger the faster it goes.
+#[derive(Component)]
+pub struct ParticleTrail {
+    pub spacing: f32,
+    pub lifetime: f32,
+    pub size: f32,
+    pub color: LinearRgba,
+    pub reference_speed: f32, // Speed at which particles get the base lifetime and size
+    pub last_position: Option<Vec3>,
+}
+
+impl ParticleTrail {
+    pub fn new(
+        spacing: f32,
+        lifetime: f32,
+        size: f32,
+        color: LinearRgba,
+        reference_speed: f32,
+    ) -> Self {
+        ParticleTrail {
+            spacing,
+            lifetime,
+            size,
+            color,
+            reference_speed,
+            last_position: None,
+        }
+    }
+}
+
+pub type PooledParticle<'a> = (
+    Entity,
+    &'a mut Particle,
+    &'a mut Transform,
+    &'a mut Visibility,
+    &'a mut MeshMaterial3d<StandardMaterial>,
+);
+
+pub type CurvedBall = (
+    Changed<physics::components::Curve>,
+    With<ball::components::BallModifiers>,
+);
@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ParticleBurst {
+    pub count: u8,
+    pub speed: f32,
+    pub spread: f32, // 0 throws everything along the direction, 1 anywhere in the hemisphere
+    pub lifetime: f32,
+    pub size: f32,
+    pub color: LinearRgba,
+}
+
+#[derive(Message, Clone, Copy, PartialEq, Debug)]
+pub struct ParticleBurstMessage {
+    pub position: Vec3,
+    pub direction: Vec3,
+    pub burst: ParticleBurst,
+}
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::{physics, states};
+
+pub mod components;
+pub mod messages;
+pub mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub fn plugin(app: &mut App) {
+    app.add_message::<messages::ParticleBurstMessage>()
+        .add_systems(
+            FixedUpdate,
+            systems::emit_impact_particles
+                .after(physics::PhysicsSet::ResolveCollisions)
+                .run_if(in_state(states::GameState::Gameplay)),
+        )
+        .add_systems(
+            Update,
+            (
+                (
+                    systems::emit_death_particles,
+                    systems::emit_super_curve_particles,
+                    systems::emit_trails,
+                ),
+                systems::spawn_particle_bursts,
+                systems::update_particles,
+            )
+                .chain()
+                .run_if(in_state(states::PauseState::Running)),
+        );
+}
@@ -0,0 +1,227 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::gameplay::{ball, paddle};
+use crate::{health, particles, physics, states};
+
+// Upper bound on pooled particles, bursts beyond it are cut short
+const MAX_PARTICLES: usize = 512;
+// Fraction of velocity lost per second
+const PARTICLE_DRAG: f32 = 3.0;
+
+pub fn emit_impact_particles(
+    mut collision_messages: MessageReader<physics::messages::CollisionMessage>,
+    impact_query: Query<&particles::components::ImpactParticles>,
+    ball_query: Query<(), With<ball::components::BallModifiers>>,
+    mut burst_messages: MessageWriter<particles::messages::ParticleBurstMessage>,
+) {
+    for message in collision_messages.read() {
+        if !ball_query.contains(message.a) {
+            continue;
+        }
+        if let Ok(impact_particles) = impact_query.get(message.b) {
+            burst_messages.write(particles::messages::ParticleBurstMessage {
+                position: message.contact_point,
+                direction: message.normal,
+                burst: impact_particles.0,
+            });
+        }
+    }
+}
+
+pub fn emit_death_particles(
+    mut death_messages: MessageReader<health::messages::DeathMessage>,
+    query: Query<(&GlobalTransform, &particles::components::DeathParticles)>,
+    mut burst_messages: MessageWriter<particles::messages::ParticleBurstMessage>,
+) {
+    for message in death_messages.read() {
+        if let Ok((transform, death_particles)) = query.get(message.entity) {
+            burst_messages.write(particles::messages::ParticleBurstMessage {
+                position: transform.translation(),
+                direction: Vec3::ZERO,
+                burst: death_particles.0,
+            });
+        }
+    }
+}
+
+pub fn emit_super_curve_particles(
+    ball_query: Query<(&Transform, &physics::components::Curve), particles::components::CurvedBall>,
+    paddle_query: Query<(
+        &paddle::components::PaddleImpactModifiers,
+        &particles::components::SuperCurveParticles,
+    )>,
+    mut burst_messages: MessageWriter<particles::messages::ParticleBurstMessage>,
+) {
+    for (transform, curve) in &ball_query {
+        for (modifiers, super_curve_particles) in &paddle_query {
+            let Some(strongest) = modifiers.curve_response.points.last().map(|point| point.y)
+            else {
+                continue;
+            };
+            if strongest > 0.0 && curve.0.abs().max_element() >= strongest {
+                burst_messages.write(particles::messages::ParticleBurstMessage {
+                    position: transform.translation,
+                    direction: Vec3::ZERO,
+                    burst: super_curve_particles.0,
+                });
+            }
+        }
+    }
+}
+
+pub fn emit_trails(
+    mut query: Query<(
+        &Transform,
+        &physics::components::Velocity,
+        &mut particles::components::ParticleTrail,
+    )>,
+    mut burst_messages: MessageWriter<particles::messages::ParticleBurstMessage>,
+) {
+    for (transform, velocity, mut trail) in &mut query {
+        let position = transform.translation;
+        if trail
+            .last_position
+            .is_some_and(|last| last.distance(position) < trail.spacing)
+        {
+            continue;
+        }
+        trail.last_position = Some(position);
+
+        let scale = (velocity.0.length() / trail.reference_speed).clamp(0.25, 2.0);
+        burst_messages.write(particles::messages::ParticleBurstMessage {
+            position,
+            direction: -velocity.0.normalize_or_zero(),
+            burst: particles::messages::ParticleBurst {
+                count: 1,
+                speed: 0.5,
+                spread: 1.0,
+                lifetime: trail.lifetime * scale,
+                size: trail.size * scale,
+                color: trail.color,
+            },
+        });
+    }
+}
+
+pub fn spawn_particle_bursts(
+    mut commands: Commands,
+    mut burst_messages: MessageReader<particles::messages::ParticleBurstMessage>,
+    mut particle_query: Query<particles::components::PooledParticle>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut particle_assets: Local<ParticleAssets>,
+) {
+    let mut free: Vec<Entity> = particle_query
+        .iter()
+        .filter(|(_, particle, ..)| !particle.active)
+        .map(|(entity, ..)| entity)
+        .collect();
+    let mut total = particle_query.iter().len();
+    let mut rng = rand::rng();
+    for message in burst_messages.read() {
+        let burst = &message.burst;
+        let material = particle_assets.material(&mut materials, burst.color);
+        for _ in 0..burst.count {
+            let random = Vec3::new(
+                rng.random_range(-1.0..1.0),
+                rng.random_range(-1.0..1.0),
+                rng.random_range(-1.0..1.0),
+            )
+            .normalize_or(Vec3::Y);
+            // A zero direction throws particles anywhere
+            let direction = (message.direction + random * burst.spread).normalize_or(random);
+            let particle = particles::components::Particle {
+                velocity: direction * burst.speed * rng.random_range(0.5..1.0),
+                age: 0.0,
+                lifetime: burst.lifetime,
+                size: burst.size,
+                active: true,
+            };
+            let transform =
+                Transform::from_translation(message.position).with_scale(Vec3::splat(burst.size));
+
+            if let Some(entity) = free.pop() {
+                let (_, mut pooled, mut pooled_transform, mut visibility, mut pooled_material) =
+                    particle_query.get_mut(entity).unwrap();
+                *pooled = particle;
+                *pooled_transform = transform;
+                *visibility = Visibility::Inherited;
+                pooled_material.0 = material.clone();
+            } else if total < MAX_PARTICLES {
+                total += 1;
+                let mesh = particle_assets
+                    .mesh
+                    .get_or_insert_with(|| meshes.add(Cuboid::from_length(1.0)))
+                    .clone();
+                commands.spawn((
+                    particle,
+                    Name::new("Particle"),
+                    Mesh3d(mesh),
+                    MeshMaterial3d(material.clone()),
+                    transform,
+                    Visibility::Inherited,
+                    DespawnOnExit(states::GameState::Gameplay),
+                ));
+            }
+        }
+    }
+}
+
+/// Built the first time each is needed and shared by every particle after that, so reusing a
+/// pooled particle only swaps handles.
+#[derive(Default)]
+pub struct ParticleAssets {
+    mesh: Option<Handle<Mesh>>,
+    materials: HashMap<[u32; 4], Handle<StandardMaterial>>,
+}
+
+impl ParticleAssets {
+    fn material(
+        &mut self,
+        materials: &mut Assets<StandardMaterial>,
+        color: LinearRgba,
+    ) -> Handle<StandardMaterial> {
+        self.materials
+            .entry(color.to_f32_array().map(f32::to_bits))
+            .or_insert_with(|| {
+                materials.add(StandardMaterial {
+                    base_color: Color::from(color),
+                    alpha_mode: AlphaMode::Blend,
+                    unlit: true,
+                    ..default()
+                })
+            })
+            .clone()
+    }
+}
+
+// Particles fade out by shrinking, so their shared materials never change
+pub fn update_particles(
+    time: Res<Time>,
+    mut query: Query<(
+        &mut particles::components::Particle,
+        &mut Transform,
+        &mut Visibility,
+    )>,
+) {
+    let delta = time.delta_secs();
+    for (mut particle, mut transform, mut visibility) in &mut query {
+        if !particle.active {
+            continue;
+        }
+        particle.age += delta;
+        if particle.age >= particle.lifetime {
+            // Back into the pool
+            particle.active = false;
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+
+        particle.velocity *= (1.0 - PARTICLE_DRAG * delta).max(0.0);
+        transform.translation += particle.velocity * delta;
+        let remaining = 1.0 - particle.age / particle.lifetime;
+        transform.scale = Vec3::splat(particle.size * remaining);
+    }
+}
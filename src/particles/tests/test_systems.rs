@@ -0,0 +1,337 @@
+use bevy::prelude::*;
+use test_case::test_case;
+
+use crate::gameplay::ball;
+use crate::test_utils;
+use crate::{health, particles, physics};
+
+const BURST: particles::messages::ParticleBurst = particles::messages::ParticleBurst {
+    count: 4,
+    speed: 2.0,
+    spread: 0.5,
+    lifetime: 1.0,
+    size: 0.5,
+    color: LinearRgba::new(1.0, 0.0, 0.0, 1.0),
+};
+
+fn create_particle_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<Time>();
+    app.init_resource::<Assets<Mesh>>();
+    app.init_resource::<Assets<StandardMaterial>>();
+    app.add_message::<particles::messages::ParticleBurstMessage>()
+        .add_systems(
+            Update,
+            (
+                particles::systems::spawn_particle_bursts,
+                particles::systems::update_particles,
+            )
+                .chain(),
+        );
+    app
+}
+
+fn write_burst(app: &mut App, count: u8) {
+    app.world_mut()
+        .write_message(particles::messages::ParticleBurstMessage {
+            position: Vec3::ONE,
+            direction: Vec3::Y,
+            burst: particles::messages::ParticleBurst { count, ..BURST },
+        });
+}
+
+fn advance(app: &mut App, seconds: f32) {
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(std::time::Duration::from_secs_f32(seconds));
+    app.update();
+}
+
+fn particle_counts(app: &mut App) -> (usize, usize) {
+    let mut query = app.world_mut().query::<&particles::components::Particle>();
+    let particles: Vec<_> = query.iter(app.world()).collect();
+    let active = particles.iter().filter(|particle| particle.active).count();
+    (particles.len(), active)
+}
+
+struct SpawnParticleBurstsCase {
+    count: u8,
+    bursts: usize,
+    expected_total: usize,
+}
+
+#[test_case(
+    SpawnParticleBurstsCase {
+        count: 4,
+        bursts: 1,
+        expected_total: 4,
+    }
+    ; "spawns one particle per count"
+)]
+#[test_case(
+    SpawnParticleBurstsCase {
+        count: 200,
+        bursts: 3,
+        expected_total: 512,
+    }
+    ; "stops at the particle cap"
+)]
+fn test_spawn_particle_bursts(case: SpawnParticleBurstsCase) {
+    let mut app = create_particle_app();
+    for _ in 0..case.bursts {
+        write_burst(&mut app, case.count);
+    }
+    app.update();
+
+    assert_eq!(
+        particle_counts(&mut app),
+        (case.expected_total, case.expected_total)
+    );
+}
+
+#[test]
+fn test_expired_particles_return_to_the_pool() {
+    let mut app = create_particle_app();
+    write_burst(&mut app, 4);
+    app.update();
+
+    advance(&mut app, 1.5);
+    assert_eq!(particle_counts(&mut app), (4, 0));
+    let mut query = app.world_mut().query::<&Visibility>();
+    assert!(
+        query
+            .iter(app.world())
+            .all(|visibility| *visibility == Visibility::Hidden)
+    );
+
+    write_burst(&mut app, 6);
+    advance(&mut app, 0.0);
+    assert_eq!(particle_counts(&mut app), (6, 6));
+    let mut query = app.world_mut().query::<&Visibility>();
+    assert!(
+        query
+            .iter(app.world())
+            .all(|visibility| *visibility == Visibility::Inherited)
+    );
+}
+
+#[test]
+fn test_update_particles_moves_and_shrinks() {
+    let mut app = create_particle_app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            particles::components::Particle {
+                velocity: Vec3::X,
+                age: 0.0,
+                lifetime: 1.0,
+                size: 2.0,
+                active: true,
+            },
+            Transform::default(),
+            Visibility::Inherited,
+        ))
+        .id();
+
+    advance(&mut app, 0.25);
+
+    let transform = app.world().get::<Transform>(entity).unwrap();
+    assert!(transform.translation.x > 0.0 && transform.translation.x < 0.25);
+    assert_eq!(transform.scale, Vec3::splat(1.5));
+}
+
+#[test]
+fn test_bursts_share_one_material_per_color() {
+    let mut app = create_particle_app();
+    write_burst(&mut app, 4);
+    app.update();
+    advance(&mut app, 1.5);
+
+    for _ in 0..3 {
+        write_burst(&mut app, 4);
+        advance(&mut app, 0.0);
+        advance(&mut app, 1.5);
+    }
+    app.world_mut()
+        .write_message(particles::messages::ParticleBurstMessage {
+            position: Vec3::ZERO,
+            direction: Vec3::ZERO,
+            burst: particles::messages::ParticleBurst {
+                color: LinearRgba::BLUE,
+                ..BURST
+            },
+        });
+    advance(&mut app, 0.0);
+
+    assert_eq!(app.world().resource::<Assets<StandardMaterial>>().len(), 2);
+    assert_eq!(particle_counts(&mut app), (4, 4));
+}
+
+struct EmitImpactParticlesCase {
+    ball: bool,
+    emitter: bool,
+    expected: bool,
+}
+
+#[test_case(
+    EmitImpactParticlesCase {
+        ball: true,
+        emitter: true,
+        expected: true,
+    }
+    ; "ball hitting an emitter bursts at the contact"
+)]
+#[test_case(
+    EmitImpactParticlesCase {
+        ball: false,
+        emitter: true,
+        expected: false,
+    }
+    ; "only balls trigger impacts"
+)]
+#[test_case(
+    EmitImpactParticlesCase {
+        ball: true,
+        emitter: false,
+        expected: false,
+    }
+    ; "entities without an emitter stay quiet"
+)]
+fn test_emit_impact_particles(case: EmitImpactParticlesCase) {
+    let mut app = App::new();
+    app.add_message::<physics::messages::CollisionMessage>()
+        .add_message::<particles::messages::ParticleBurstMessage>()
+        .add_systems(Update, particles::systems::emit_impact_particles);
+    let a = if case.ball {
+        app.world_mut()
+            .spawn(ball::components::BallModifiers::starting())
+            .id()
+    } else {
+        app.world_mut().spawn_empty().id()
+    };
+    let b = if case.emitter {
+        app.world_mut()
+            .spawn(particles::components::ImpactParticles(BURST))
+            .id()
+    } else {
+        app.world_mut().spawn_empty().id()
+    };
+
+    app.world_mut()
+        .write_message(physics::messages::CollisionMessage {
+            a,
+            b,
+            normal: Vec3::Z,
+            contact_point: Vec3::new(1.0, 2.0, 3.0),
+            penetration: 0.1,
+        });
+    app.update();
+
+    let expected: Vec<_> = case
+        .expected
+        .then_some(particles::messages::ParticleBurstMessage {
+            position: Vec3::new(1.0, 2.0, 3.0),
+            direction: Vec3::Z,
+            burst: BURST,
+        })
+        .into_iter()
+        .collect();
+    test_utils::assertions::assert_messages(&app, &expected);
+}
+
+#[test]
+fn test_emit_death_particles() {
+    let mut app = App::new();
+    app.add_message::<health::messages::DeathMessage>()
+        .add_message::<particles::messages::ParticleBurstMessage>()
+        .add_systems(Update, particles::systems::emit_death_particles);
+    let entity = app
+        .world_mut()
+        .spawn((
+            particles::components::DeathParticles(BURST),
+            GlobalTransform::from_translation(Vec3::X),
+        ))
+        .id();
+
+    app.world_mut()
+        .write_message(health::messages::DeathMessage {
+            entity,
+            source: None,
+            kind: health::messages::DamageKind::Impact,
+        });
+    app.update();
+
+    test_utils::assertions::assert_messages(
+        &app,
+        &[particles::messages::ParticleBurstMessage {
+            position: Vec3::X,
+            direction: Vec3::ZERO,
+            burst: BURST,
+        }],
+    );
+}
+
+struct EmitTrailsCase {
+    last_position: Option<Vec3>,
+    speed: f32,
+    expected_lifetime: Option<f32>,
+}
+
+#[test_case(
+    EmitTrailsCase {
+        last_position: None,
+        speed: 10.0,
+        expected_lifetime: Some(0.5),
+    }
+    ; "first frame leaves a particle"
+)]
+#[test_case(
+    EmitTrailsCase {
+        last_position: Some(Vec3::new(0.0, 0.0, 0.1)),
+        speed: 10.0,
+        expected_lifetime: None,
+    }
+    ; "nothing until the ball has moved the spacing"
+)]
+#[test_case(
+    EmitTrailsCase {
+        last_position: Some(Vec3::new(0.0, 0.0, 1.0)),
+        speed: 40.0,
+        expected_lifetime: Some(2.0),
+    }
+    ; "faster balls leave longer lived particles"
+)]
+#[test_case(
+    EmitTrailsCase {
+        last_position: Some(Vec3::new(0.0, 0.0, 1.0)),
+        speed: 1.0,
+        expected_lifetime: Some(0.25),
+    }
+    ; "slow balls are clamped to the shortest lifetime"
+)]
+fn test_emit_trails(case: EmitTrailsCase) {
+    let mut app = App::new();
+    app.add_message::<particles::messages::ParticleBurstMessage>()
+        .add_systems(Update, particles::systems::emit_trails);
+    let mut trail = particles::components::ParticleTrail::new(0.5, 1.0, 0.2, BURST.color, 20.0);
+    trail.last_position = case.last_position;
+    app.world_mut().spawn((
+        trail,
+        Transform::default(),
+        physics::components::Velocity(Vec3::Z * case.speed),
+    ));
+    app.update();
+
+    let messages = app
+        .world()
+        .resource::<Messages<particles::messages::ParticleBurstMessage>>();
+    let lifetimes: Vec<f32> = messages
+        .get_cursor()
+        .read(messages)
+        .map(|message| message.burst.lifetime)
+        .collect();
+    assert_eq!(
+        lifetimes,
+        case.expected_lifetime.into_iter().collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseMenuButtonAction {
+    Resume,
+    Restart,
+    Settings,
+    QuitToMenu,
+}
+
+pub type PauseMenuInteraction<'a> = (&'a Interaction, &'a PauseMenuButtonAction);
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+use crate::states;
+
+#[cfg(test)]
+mod tests;
+
+pub mod components;
+pub mod systems;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            // The settings screen captures key presses for rebinding
+            systems::toggle_pause.run_if(not(in_state(states::MenuState::Settings))),
+            systems::pause_on_focus_lost,
+        )
+            .run_if(in_state(states::GameState::Gameplay)),
+    )
+    .add_systems(
+        Update,
+        systems::pause_menu_action.run_if(in_state(states::MenuState::Pause)),
+    )
+    .add_systems(OnEnter(states::PauseState::Paused), systems::pause_time)
+    .add_systems(OnExit(states::PauseState::Paused), systems::resume_time)
+    .add_systems(
+        OnEnter(states::MenuState::Pause),
+        systems::pause_menu_ui_setup,
+    );
+}
@@ -0,0 +1,154 @@
+use bevy::prelude::*;
+
+use crate::input;
+use crate::main_menu::{self, systems::TEXT_COLOR};
+use crate::pause_menu::components;
+use crate::states;
+use crate::theme;
+
+const OVERLAY_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+
+pub fn toggle_pause(
+    actions: input::systems::ActionInput,
+    pause_state: Res<State<states::PauseState>>,
+    mut next_pause_state: ResMut<NextState<states::PauseState>>,
+    mut menu_state: ResMut<NextState<states::MenuState>>,
+) {
+    if !actions.just_pressed(input::resources::Action::Pause) {
+        return;
+    }
+
+    match pause_state.get() {
+        states::PauseState::Running => {
+            next_pause_state.set(states::PauseState::Paused);
+            menu_state.set(states::MenuState::Pause);
+        }
+        states::PauseState::Paused => {
+            next_pause_state.set(states::PauseState::Running);
+            menu_state.set(states::MenuState::Disabled);
+        }
+    }
+}
+
+pub fn pause_on_focus_lost(
+    mut focus_messages: MessageReader<bevy::window::WindowFocused>,
+    pause_state: Res<State<states::PauseState>>,
+    mut next_pause_state: ResMut<NextState<states::PauseState>>,
+    mut menu_state: ResMut<NextState<states::MenuState>>,
+) {
+    let lost_focus = focus_messages.read().any(|message| !message.focused);
+    if lost_focus && *pause_state.get() == states::PauseState::Running {
+        next_pause_state.set(states::PauseState::Paused);
+        menu_state.set(states::MenuState::Pause);
+    }
+}
+
+// Pausing virtual time stops FixedUpdate from running, which freezes all of physics
+pub fn pause_time(
+    mut time: ResMut<Time<Virtual>>,
+    mut cursor_options: Query<&mut bevy::window::CursorOptions>,
+) {
+    time.pause();
+    for mut cursor_options in &mut cursor_options {
+        cursor_options.visible = true;
+        cursor_options.grab_mode = bevy::window::CursorGrabMode::None;
+    }
+}
+
+pub fn resume_time(mut time: ResMut<Time<Virtual>>) {
+    time.unpause();
+}
+
+pub fn pause_menu_action(
+    interaction_query: Query<
+        components::PauseMenuInteraction,
+        main_menu::components::RecentButtonInteraction,
+    >,
+    mut pause_state: ResMut<NextState<states::PauseState>>,
+    mut menu_state: ResMut<NextState<states::MenuState>>,
+    mut game_state: ResMut<NextState<states::GameState>>,
+) {
+    for (interaction, action) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match action {
+            components::PauseMenuButtonAction::Resume => {
+                pause_state.set(states::PauseState::Running);
+                menu_state.set(states::MenuState::Disabled);
+            }
+            components::PauseMenuButtonAction::Restart => {
+                menu_state.set(states::MenuState::Disabled);
+                game_state.set(states::GameState::Restarting);
+            }
+            components::PauseMenuButtonAction::Settings => {
+                menu_state.set(states::MenuState::Settings);
+            }
+            components::PauseMenuButtonAction::QuitToMenu => {
+                game_state.set(states::GameState::Menu);
+            }
+        }
+    }
+}
+
+pub fn pause_menu_ui_setup(mut commands: Commands, theme: Res<theme::resources::Theme>) {
+    commands
+        .spawn((
+            DespawnOnExit(states::MenuState::Pause),
+            Node {
+                width: percent(100),
+                height: percent(100),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BackgroundColor(OVERLAY_COLOR),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(theme.menu_background),
+                    theme::components::ThemedBackground,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("Paused"),
+                        TextFont {
+                            font_size: 67.0,
+                            ..default()
+                        },
+                        TextColor(TEXT_COLOR),
+                        Node {
+                            margin: UiRect::all(px(50)),
+                            ..default()
+                        },
+                    ));
+                    for (action, label) in [
+                        (components::PauseMenuButtonAction::Resume, "Resume"),
+                        (components::PauseMenuButtonAction::Restart, "Restart"),
+                        (components::PauseMenuButtonAction::Settings, "Settings"),
+                        (
+                            components::PauseMenuButtonAction::QuitToMenu,
+                            "Quit to Menu",
+                        ),
+                    ] {
+                        parent.spawn((
+                            Button,
+                            main_menu::systems::button_node(),
+                            BackgroundColor(main_menu::systems::NORMAL_BUTTON),
+                            action,
+                            children![(
+                                Text::new(label),
+                                main_menu::systems::button_text_font(),
+                                TextColor(TEXT_COLOR),
+                            )],
+                        ));
+                    }
+                });
+        });
+}
@@ -0,0 +1,162 @@
+use bevy::prelude::*;
+use test_case::test_case;
+
+use crate::input;
+use crate::pause_menu::{components, systems};
+use crate::states;
+
+fn gameplay_app(pause_state: states::PauseState) -> App {
+    let mut app = App::new();
+    app.add_plugins((bevy::state::app::StatesPlugin, states::plugin));
+    app.insert_state(states::GameState::Gameplay);
+    // Let the gameplay sub states come into existence before changing them
+    app.update();
+    app.world_mut()
+        .resource_mut::<NextState<states::PauseState>>()
+        .set(pause_state);
+    app.update();
+    app
+}
+
+fn assert_states(app: &App, pause_state: states::PauseState, menu_state: states::MenuState) {
+    assert_eq!(
+        *app.world().resource::<State<states::PauseState>>(),
+        pause_state
+    );
+    assert_eq!(
+        *app.world().resource::<State<states::MenuState>>(),
+        menu_state
+    );
+}
+
+struct TogglePauseCase {
+    starting_state: states::PauseState,
+    press_escape: bool,
+    expected_pause_state: states::PauseState,
+    expected_menu_state: states::MenuState,
+}
+
+#[test_case(
+    TogglePauseCase {
+        starting_state: states::PauseState::Running,
+        press_escape: true,
+        expected_pause_state: states::PauseState::Paused,
+        expected_menu_state: states::MenuState::Pause,
+    }
+; "escape pauses a running game")]
+#[test_case(
+    TogglePauseCase {
+        starting_state: states::PauseState::Paused,
+        press_escape: true,
+        expected_pause_state: states::PauseState::Running,
+        expected_menu_state: states::MenuState::Disabled,
+    }
+; "escape resumes a paused game")]
+#[test_case(
+    TogglePauseCase {
+        starting_state: states::PauseState::Running,
+        press_escape: false,
+        expected_pause_state: states::PauseState::Running,
+        expected_menu_state: states::MenuState::Disabled,
+    }
+; "no input keeps running")]
+fn test_toggle_pause(case: TogglePauseCase) {
+    let mut app = gameplay_app(case.starting_state);
+    let mut keyboard_input = ButtonInput::<KeyCode>::default();
+    if case.press_escape {
+        keyboard_input.press(KeyCode::Escape);
+    }
+    app.insert_resource(keyboard_input);
+    app.init_resource::<ButtonInput<MouseButton>>();
+    app.init_resource::<input::resources::Bindings>();
+    app.add_systems(Update, systems::toggle_pause);
+
+    app.update();
+    app.update();
+
+    assert_states(&app, case.expected_pause_state, case.expected_menu_state);
+}
+
+#[test_case(false, states::PauseState::Paused, states::MenuState::Pause; "losing focus pauses")]
+#[test_case(true, states::PauseState::Running, states::MenuState::Disabled; "gaining focus does nothing")]
+fn test_pause_on_focus_lost(
+    focused: bool,
+    expected_pause_state: states::PauseState,
+    expected_menu_state: states::MenuState,
+) {
+    let mut app = gameplay_app(states::PauseState::Running);
+    app.add_message::<bevy::window::WindowFocused>();
+    app.add_systems(Update, systems::pause_on_focus_lost);
+    let window = app.world_mut().spawn_empty().id();
+    app.world_mut()
+        .write_message(bevy::window::WindowFocused { window, focused });
+
+    app.update();
+    app.update();
+
+    assert_states(&app, expected_pause_state, expected_menu_state);
+}
+
+struct PauseMenuActionCase {
+    action: components::PauseMenuButtonAction,
+    expected_game_state: states::GameState,
+    expected_menu_state: states::MenuState,
+}
+
+#[test_case(
+    PauseMenuActionCase {
+        action: components::PauseMenuButtonAction::Resume,
+        expected_game_state: states::GameState::Gameplay,
+        expected_menu_state: states::MenuState::Disabled,
+    }
+; "resume closes the menu")]
+#[test_case(
+    PauseMenuActionCase {
+        action: components::PauseMenuButtonAction::Restart,
+        expected_game_state: states::GameState::Restarting,
+        expected_menu_state: states::MenuState::Disabled,
+    }
+; "restart leaves gameplay to set the level up again")]
+#[test_case(
+    PauseMenuActionCase {
+        action: components::PauseMenuButtonAction::Settings,
+        expected_game_state: states::GameState::Gameplay,
+        expected_menu_state: states::MenuState::Settings,
+    }
+; "settings opens the settings screen")]
+#[test_case(
+    PauseMenuActionCase {
+        action: components::PauseMenuButtonAction::QuitToMenu,
+        expected_game_state: states::GameState::Menu,
+        expected_menu_state: states::MenuState::Disabled,
+    }
+; "quit to menu leaves gameplay")]
+fn test_pause_menu_action(case: PauseMenuActionCase) {
+    let mut app = gameplay_app(states::PauseState::Paused);
+    app.add_systems(Update, systems::pause_menu_action);
+    app.world_mut()
+        .spawn((Button, case.action, Interaction::Pressed));
+
+    app.update();
+    app.update();
+
+    assert_eq!(
+        *app.world().resource::<State<states::GameState>>(),
+        case.expected_game_state
+    );
+    assert_eq!(
+        *app.world().resource::<State<states::MenuState>>(),
+        case.expected_menu_state
+    );
+}
+
+#[test]
+fn test_pause_time_pauses_virtual_time() {
+    let mut app = App::new();
+    app.init_resource::<Time<Virtual>>();
+    app.add_systems(Update, systems::pause_time);
+
+    app.update();
+
+    assert!(app.world().resource::<Time<Virtual>>().is_paused());
+}
@@ -0,0 +1,73 @@
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::{Serialize, de::DeserializeOwned};
+
+#[cfg(test)]
+mod tests;
+
+/// Directory where brickochet keeps its save and config files, e.g.
+/// `~/.config/brickochet` on Linux.
+pub fn config_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("brickochet"))
+}
+
+/// Loads `file_name` from the config directory, falling back to the default value
+/// when the file is missing or cannot be parsed.
+pub fn load<T: DeserializeOwned + Default>(file_name: &str) -> T {
+    match config_dir() {
+        Some(dir) => load_from(&dir.join(file_name)),
+        None => {
+            warn!("No config directory available, using defaults for {file_name}");
+            T::default()
+        }
+    }
+}
+
+/// Saves `value` as `file_name` in the config directory, logging instead of failing
+/// so a read-only home directory never interrupts play.
+pub fn save<T: Serialize>(file_name: &str, value: &T) {
+    match config_dir() {
+        Some(dir) => save_to(&dir.join(file_name), value),
+        None => warn!("No config directory available, not saving {file_name}"),
+    }
+}
+
+pub fn load_from<T: DeserializeOwned + Default>(path: &Path) -> T {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) => {
+            warn!("Could not read {}: {error}, using defaults", path.display());
+            return T::default();
+        }
+    };
+
+    ron::from_str(&contents).unwrap_or_else(|error| {
+        warn!(
+            "Could not parse {}: {error}, using defaults",
+            path.display()
+        );
+        T::default()
+    })
+}
+
+pub fn save_to<T: Serialize>(path: &Path, value: &T) {
+    let contents = match ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()) {
+        Ok(contents) => contents,
+        Err(error) => {
+            warn!("Could not serialise {}: {error}", path.display());
+            return;
+        }
+    };
+
+    if let Some(parent) = path.parent()
+        && let Err(error) = std::fs::create_dir_all(parent)
+    {
+        warn!("Could not create {}: {error}", parent.display());
+        return;
+    }
+
+    if let Err(error) = std::fs::write(path, contents) {
+        warn!("Could not write {}: {error}", path.display());
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use test_case::test_case;
+
+use crate::persistence;
+
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+struct Saved {
+    value: u32,
+    name: String,
+}
+
+fn temp_path(file_name: &str) -> std::path::PathBuf {
+    std::env::temp_dir()
+        .join(format!("brickochet-test-{}", std::process::id()))
+        .join(file_name)
+}
+
+#[test]
+fn test_save_then_load_round_trips() {
+    let path = temp_path("round_trip.ron");
+    let saved = Saved {
+        value: 7,
+        name: "seven".into(),
+    };
+
+    persistence::save_to(&path, &saved);
+    let loaded: Saved = persistence::load_from(&path);
+
+    assert_eq!(loaded, saved);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test_case(None; "missing file")]
+#[test_case(Some("not ron at all {"); "invalid file")]
+fn test_load_falls_back_to_default(contents: Option<&str>) {
+    let path = temp_path(&format!("fallback_{}.ron", contents.is_some()));
+    if let Some(contents) = contents {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, contents).unwrap();
+    }
+
+    let loaded: Saved = persistence::load_from(&path);
+
+    assert_eq!(loaded, Saved::default());
+    let _ = std::fs::remove_file(path);
+}
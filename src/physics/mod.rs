@@ -4,6 +4,7 @@ pub mod components;
 pub mod math;
 pub mod messages;
 pub mod systems;
+pub mod trajectory;
 
 #[cfg(test)]
 mod tests;
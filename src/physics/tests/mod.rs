@@ -1,2 +1,3 @@
 mod test_math;
 mod test_systems;
+mod test_trajectory;
@@ -0,0 +1,132 @@
+use bevy::math::{Vec2, Vec3};
+use test_case::test_case;
+
+use crate::physics::trajectory;
+
+struct PredictTrajectoryCase {
+    ball: trajectory::PredictedBall,
+    obstacles: Vec<trajectory::Obstacle>,
+    plane_z: f32,
+    delta_secs: f32,
+    max_steps: usize,
+    expected: trajectory::Trajectory,
+}
+
+#[test_case(
+    PredictTrajectoryCase {
+        ball: trajectory::PredictedBall {
+            position: Vec3::ZERO,
+            velocity: Vec3::new(0.0, 0.0, 10.0),
+            curve: Vec2::ZERO,
+            radius: 0.5,
+        },
+        obstacles: vec![],
+        plane_z: 4.5,
+        delta_secs: 0.1,
+        max_steps: 100,
+        expected: trajectory::Trajectory {
+            points: vec![
+                Vec3::new(0.0, 0.0, 1.0),
+                Vec3::new(0.0, 0.0, 2.0),
+                Vec3::new(0.0, 0.0, 3.0),
+                Vec3::new(0.0, 0.0, 4.0),
+                Vec3::new(0.0, 0.0, 4.5),
+            ],
+            plane_crossing: Some(Vec3::new(0.0, 0.0, 4.5)),
+        },
+    }
+    ; "stops where it crosses the paddle plane"
+)]
+#[test_case(
+    PredictTrajectoryCase {
+        ball: trajectory::PredictedBall {
+            position: Vec3::ZERO,
+            velocity: Vec3::new(10.0, 0.0, 10.0),
+            curve: Vec2::ZERO,
+            radius: 0.5,
+        },
+        obstacles: vec![trajectory::Obstacle {
+            center: Vec3::new(3.5, 0.0, 0.0),
+            half_extents: Vec3::new(0.5, 10.0, 100.0),
+        }],
+        plane_z: 5.0,
+        delta_secs: 0.1,
+        max_steps: 100,
+        expected: trajectory::Trajectory {
+            points: vec![
+                Vec3::new(1.0, 0.0, 1.0),
+                Vec3::new(2.0, 0.0, 2.0),
+                Vec3::new(2.5, 0.0, 3.0),
+                Vec3::new(1.5, 0.0, 4.0),
+                Vec3::new(0.5, 0.0, 5.0),
+            ],
+            plane_crossing: Some(Vec3::new(0.5, 0.0, 5.0)),
+        },
+    }
+    ; "pushed out of and reflected off a wall"
+)]
+#[test_case(
+    PredictTrajectoryCase {
+        ball: trajectory::PredictedBall {
+            position: Vec3::ZERO,
+            velocity: Vec3::new(0.0, 0.0, 10.0),
+            curve: Vec2::new(2.0, -4.0),
+            radius: 0.5,
+        },
+        obstacles: vec![],
+        plane_z: 100.0,
+        delta_secs: 0.5,
+        max_steps: 2,
+        expected: trajectory::Trajectory {
+            points: vec![Vec3::new(0.5, -1.0, 5.0), Vec3::new(1.5, -3.0, 10.0)],
+            plane_crossing: None,
+        },
+    }
+    ; "curve bends the path before each move"
+)]
+#[test_case(
+    PredictTrajectoryCase {
+        ball: trajectory::PredictedBall {
+            position: Vec3::new(0.0, 0.0, 5.0),
+            velocity: Vec3::new(0.0, 0.0, -10.0),
+            curve: Vec2::ZERO,
+            radius: 0.5,
+        },
+        obstacles: vec![],
+        plane_z: 4.0,
+        delta_secs: 0.1,
+        max_steps: 3,
+        expected: trajectory::Trajectory {
+            points: vec![
+                Vec3::new(0.0, 0.0, 4.0),
+                Vec3::new(0.0, 0.0, 3.0),
+                Vec3::new(0.0, 0.0, 2.0),
+            ],
+            plane_crossing: None,
+        },
+    }
+    ; "moving away from the paddle never crosses"
+)]
+fn test_predict_trajectory(case: PredictTrajectoryCase) {
+    let predicted = trajectory::predict_trajectory(
+        case.ball,
+        &case.obstacles,
+        case.plane_z,
+        case.delta_secs,
+        case.max_steps,
+    );
+
+    assert_eq!(
+        predicted.plane_crossing.is_some(),
+        case.expected.plane_crossing.is_some()
+    );
+    if let (Some(crossing), Some(expected)) =
+        (predicted.plane_crossing, case.expected.plane_crossing)
+    {
+        assert!(crossing.abs_diff_eq(expected, 1e-4));
+    }
+    assert_eq!(predicted.points.len(), case.expected.points.len());
+    for (point, expected) in predicted.points.iter().zip(&case.expected.points) {
+        assert!(point.abs_diff_eq(*expected, 1e-4), "{point} != {expected}");
+    }
+}
@@ -0,0 +1,91 @@
+use bevy::math::{Vec2, Vec3};
+
+use crate::physics;
+
+/// Ball state the prediction starts from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PredictedBall {
+    pub position: Vec3,
+    pub velocity: Vec3,
+    pub curve: Vec2,
+    pub radius: f32,
+}
+
+/// Static cuboid the predicted path bounces off.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Obstacle {
+    pub center: Vec3,
+    pub half_extents: Vec3,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Trajectory {
+    pub points: Vec<Vec3>, // Ball position after each step
+    pub plane_crossing: Option<Vec3>,
+}
+
+/// Steps the ball forward the same way the physics systems do, curving, moving and then
+/// reflecting off every obstacle it overlaps. Stops after `max_steps` or once the ball crosses
+/// `plane_z` heading toward +z, where the paddle faces the playfield.
+pub fn predict_trajectory(
+    ball: PredictedBall,
+    obstacles: &[Obstacle],
+    plane_z: f32,
+    delta_secs: f32,
+    max_steps: usize,
+) -> Trajectory {
+    let mut position = ball.position;
+    let mut velocity = ball.velocity;
+    let mut trajectory = Trajectory::default();
+    for _ in 0..max_steps {
+        // apply_curve
+        velocity.x += ball.curve.x * delta_secs;
+        velocity.y += ball.curve.y * delta_secs;
+
+        // apply_velocity
+        let previous = position;
+        position += velocity * delta_secs;
+
+        if previous.z < plane_z && position.z >= plane_z {
+            let t = (plane_z - previous.z) / (position.z - previous.z);
+            let crossing = previous.lerp(position, t);
+            trajectory.points.push(crossing);
+            trajectory.plane_crossing = Some(crossing);
+            break;
+        }
+
+        // detect_collisions and resolve_sphere_aabb_collision
+        let mut total_normal = Vec3::ZERO;
+        let mut max_penetration: f32 = 0.0;
+        for obstacle in obstacles {
+            if !physics::math::sphere_aabb_intersects(
+                position,
+                ball.radius,
+                obstacle.center,
+                obstacle.half_extents,
+            ) {
+                continue;
+            }
+            total_normal += physics::math::sphere_aabb_contact_normal(
+                position,
+                ball.radius,
+                obstacle.center,
+                obstacle.half_extents,
+            );
+            let contact_point = physics::math::closest_point_on_aabb(
+                position,
+                obstacle.center,
+                obstacle.half_extents,
+            );
+            max_penetration = max_penetration.max(ball.radius - contact_point.distance(position));
+        }
+        if total_normal != Vec3::ZERO {
+            let normal = total_normal.normalize();
+            position += normal * max_penetration;
+            velocity = velocity.reflect(normal);
+        }
+
+        trajectory.points.push(position);
+    }
+    trajectory
+}
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+
+/// End value of a tween, the variant decides which property it animates.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TweenTarget {
+    BaseColor(LinearRgba),
+    Emissive(LinearRgba),
+    Scale(Vec3),
+    Translation(Vec3),
+}
+
+impl TweenTarget {
+    pub fn same_property(&self, other: &TweenTarget) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    fn lerp(&self, end: &TweenTarget, t: f32) -> TweenTarget {
+        match (*self, *end) {
+            (TweenTarget::BaseColor(from), TweenTarget::BaseColor(to)) => {
+                TweenTarget::BaseColor(from.mix(&to, t))
+            }
+            (TweenTarget::Emissive(from), TweenTarget::Emissive(to)) => {
+                TweenTarget::Emissive(from.mix(&to, t))
+            }
+            (TweenTarget::Scale(from), TweenTarget::Scale(to)) => {
+                TweenTarget::Scale(from.lerp(to, t))
+            }
+            (TweenTarget::Translation(from), TweenTarget::Translation(to)) => {
+                TweenTarget::Translation(from.lerp(to, t))
+            }
+            _ => *end,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TweenRepeat {
+    Once,
+    PingPong, // Run back and forth
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Tween {
+    pub from: TweenTarget,
+    pub to: TweenTarget,
+    pub duration: f32,
+    pub easing: EaseFunction,
+    pub repeat: TweenRepeat,
+    pub elapsed: f32,
+}
+
+impl Tween {
+    pub fn value(&self) -> TweenTarget {
+        let progress = if self.duration > 0.0 {
+            self.elapsed / self.duration
+        } else {
+            1.0
+        };
+        let t = match self.repeat {
+            TweenRepeat::Once => progress.min(1.0),
+            TweenRepeat::PingPong => 1.0 - ((progress % 2.0) - 1.0).abs(),
+        };
+        self.from.lerp(&self.to, self.easing.sample_clamped(t))
+    }
+
+    /// Returns whether the tween has run its course, looping tweens never do.
+    pub fn advance(&mut self, delta: f32) -> bool {
+        self.elapsed += delta;
+        self.repeat == TweenRepeat::Once && self.elapsed >= self.duration
+    }
+}
+
+/// Tweens running on an entity, at most one per property.
+#[derive(Component, Default)]
+pub struct Tweens(pub Vec<Tween>);
+
+impl Tweens {
+    /// Replaces any tween already animating the same property, the caller starts the new one
+    /// from where the old one got to so the two blend without a jump.
+    pub fn start(&mut self, tween: Tween) {
+        self.0
+            .retain(|running| !running.to.same_property(&tween.to));
+        self.0.push(tween);
+    }
+
+    pub fn current(&self, target: &TweenTarget) -> Option<TweenTarget> {
+        self.0
+            .iter()
+            .find(|running| running.to.same_property(target))
+            .map(Tween::value)
+    }
+}
+
+/// Where a new tween reads the current value of its property from.
+pub type TweenSources<'a> = (
+    Option<&'a mut Tweens>,
+    Option<&'a Transform>,
+    Option<&'a MeshMaterial3d<StandardMaterial>>,
+);
@@ -1,8 +1,20 @@
 use bevy::prelude::*;
 
+use crate::rendering;
+
 #[derive(Message, Clone, Copy, Debug, PartialEq)]
 pub struct MaterialColorsChangedMessage {
     pub entity: Entity,
     pub base_color: Option<bevy::color::Color>,
     pub emissive: Option<LinearRgba>,
 }
+
+/// Animates a property of `entity` from its current value to `target`.
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct TweenMessage {
+    pub entity: Entity,
+    pub target: rendering::components::TweenTarget,
+    pub duration: f32,
+    pub easing: EaseFunction,
+    pub repeat: rendering::components::TweenRepeat,
+}
@@ -1,6 +1,10 @@
 use bevy::prelude::*;
 
+use crate::states;
+
+pub mod components;
 pub mod messages;
+pub mod resources;
 pub mod systems;
 
 #[cfg(test)]
@@ -8,14 +12,33 @@ mod tests;
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub enum RenderingSet {
+    Tween,
     Integrate,
 }
 
 pub fn plugin(app: &mut App) {
     app.add_message::<messages::MaterialColorsChangedMessage>()
-        .configure_sets(PostUpdate, RenderingSet::Integrate)
+        .add_message::<messages::TweenMessage>()
+        .init_resource::<resources::MaterialCache>()
+        .configure_sets(
+            PostUpdate,
+            (
+                RenderingSet::Tween.before(TransformSystems::Propagate),
+                RenderingSet::Integrate,
+            )
+                .chain(),
+        )
         .add_systems(
             PostUpdate,
-            systems::update_material_color.in_set(RenderingSet::Integrate),
+            (
+                (systems::start_tweens, systems::advance_tweens)
+                    .chain()
+                    .in_set(RenderingSet::Tween),
+                systems::update_material_color.in_set(RenderingSet::Integrate),
+            ),
+        )
+        .add_systems(
+            OnExit(states::GameState::Gameplay),
+            systems::clear_material_cache,
         );
 }
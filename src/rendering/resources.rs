@@ -0,0 +1,69 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+/// Shares `StandardMaterial`s between entities that only differ in colors or base color texture,
+/// so changing one entity's look swaps its handle instead of editing an asset others may use.
+#[derive(Resource, Default)]
+pub struct MaterialCache {
+    variants: HashMap<MaterialKey, Handle<StandardMaterial>>,
+    families: HashMap<AssetId<StandardMaterial>, AssetId<StandardMaterial>>, // Variant to root
+}
+
+#[derive(Hash, PartialEq, Eq)]
+struct MaterialKey {
+    family: AssetId<StandardMaterial>,
+    base_color: [i32; 4],
+    emissive: [i32; 4],
+    texture: Option<AssetId<Image>>,
+}
+
+impl MaterialKey {
+    fn new(family: AssetId<StandardMaterial>, material: &StandardMaterial) -> Self {
+        // Colors are compared in 1/255 steps so animated colors reuse a bounded set of variants
+        let quantize = |color: LinearRgba| color.to_f32_array().map(|c| (c * 255.0).round() as i32);
+        MaterialKey {
+            family,
+            base_color: quantize(material.base_color.to_linear()),
+            emissive: quantize(material.emissive),
+            texture: material.base_color_texture.as_ref().map(Handle::id),
+        }
+    }
+}
+
+impl MaterialCache {
+    /// Returns the shared variant of `handle` with `edit` applied, adding it if no entity uses it
+    /// yet. Every other property is taken from the material the family started from.
+    pub fn variant(
+        &mut self,
+        materials: &mut Assets<StandardMaterial>,
+        handle: &Handle<StandardMaterial>,
+        edit: impl FnOnce(&mut StandardMaterial),
+    ) -> Option<Handle<StandardMaterial>> {
+        let mut material = materials.get(handle)?.clone();
+        let family = match self.families.get(&handle.id()) {
+            Some(family) => *family,
+            None => {
+                // First time this root is seen, so changing back to its look returns it
+                self.families.insert(handle.id(), handle.id());
+                self.variants
+                    .insert(MaterialKey::new(handle.id(), &material), handle.clone());
+                handle.id()
+            }
+        };
+
+        edit(&mut material);
+        let key = MaterialKey::new(family, &material);
+        if let Some(variant) = self.variants.get(&key) {
+            return Some(variant.clone());
+        }
+        let variant = materials.add(material);
+        self.families.insert(variant.id(), family);
+        self.variants.insert(key, variant.clone());
+        Some(variant)
+    }
+
+    pub fn clear(&mut self) {
+        self.variants.clear();
+        self.families.clear();
+    }
+}
@@ -4,19 +4,141 @@ use crate::rendering;
 
 pub fn update_material_color(
     mut materials: ResMut<Assets<StandardMaterial>>,
-    mut query: Query<&MeshMaterial3d<StandardMaterial>>,
+    mut cache: ResMut<rendering::resources::MaterialCache>,
+    mut query: Query<&mut MeshMaterial3d<StandardMaterial>>,
     mut messages: MessageReader<rendering::messages::MaterialColorsChangedMessage>,
 ) {
     for message in messages.read() {
-        if let Ok(material_handle) = query.get_mut(message.entity) {
-            let material = materials.get_mut(material_handle.id()).unwrap();
-            if let Some(base_color) = message.base_color {
-                material.base_color = base_color;
+        if let Ok(mut material_handle) = query.get_mut(message.entity) {
+            let Some(variant) = cache.variant(&mut materials, &material_handle.0, |material| {
+                if let Some(base_color) = message.base_color {
+                    material.base_color = base_color;
+                }
+
+                if let Some(emissive) = message.emissive {
+                    material.emissive = emissive;
+                }
+            }) else {
+                continue;
+            };
+            if variant != material_handle.0 {
+                material_handle.0 = variant;
             }
+        }
+    }
+}
 
-            if let Some(emissive) = message.emissive {
-                material.emissive = emissive;
+// Everything using the cached variants is despawned along with gameplay
+pub fn clear_material_cache(mut cache: ResMut<rendering::resources::MaterialCache>) {
+    cache.clear();
+}
+
+pub fn start_tweens(
+    mut commands: Commands,
+    mut messages: MessageReader<rendering::messages::TweenMessage>,
+    mut query: Query<rendering::components::TweenSources>,
+    materials: Res<Assets<StandardMaterial>>,
+) {
+    // Tweens for entities without any are gathered here, so several in one frame all start
+    let mut added: bevy::platform::collections::HashMap<Entity, rendering::components::Tweens> =
+        default();
+    for message in messages.read() {
+        let Ok((tweens, transform, material)) = query.get_mut(message.entity) else {
+            continue;
+        };
+        let running = match &tweens {
+            Some(tweens) => tweens.current(&message.target),
+            None => added
+                .get(&message.entity)
+                .and_then(|tweens| tweens.current(&message.target)),
+        };
+        let material = material.and_then(|material| materials.get(material.id()));
+        let from = running.or(match message.target {
+            rendering::components::TweenTarget::BaseColor(_) => material.map(|material| {
+                rendering::components::TweenTarget::BaseColor(material.base_color.to_linear())
+            }),
+            rendering::components::TweenTarget::Emissive(_) => material
+                .map(|material| rendering::components::TweenTarget::Emissive(material.emissive)),
+            rendering::components::TweenTarget::Scale(_) => transform
+                .map(|transform| rendering::components::TweenTarget::Scale(transform.scale)),
+            rendering::components::TweenTarget::Translation(_) => transform.map(|transform| {
+                rendering::components::TweenTarget::Translation(transform.translation)
+            }),
+        });
+        let Some(from) = from else {
+            continue;
+        };
+
+        let tween = rendering::components::Tween {
+            from,
+            to: message.target,
+            duration: message.duration,
+            easing: message.easing,
+            repeat: message.repeat,
+            elapsed: 0.0,
+        };
+        match tweens {
+            Some(mut tweens) => tweens.start(tween),
+            None => added.entry(message.entity).or_default().start(tween),
+        }
+    }
+    // Dying entities may be despawned before the commands apply
+    for (entity, tweens) in added {
+        commands.entity(entity).try_insert(tweens);
+    }
+}
+
+pub fn advance_tweens(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(
+        Entity,
+        &mut rendering::components::Tweens,
+        Option<&mut Transform>,
+    )>,
+    mut material_colors_changed_messages: MessageWriter<
+        rendering::messages::MaterialColorsChangedMessage,
+    >,
+) {
+    for (entity, mut tweens, mut transform) in &mut query {
+        tweens.0.retain_mut(|tween| {
+            let finished = tween.advance(time.delta_secs());
+            match tween.value() {
+                rendering::components::TweenTarget::BaseColor(color) => {
+                    material_colors_changed_messages.write(
+                        rendering::messages::MaterialColorsChangedMessage {
+                            entity,
+                            base_color: Some(Color::from(color)),
+                            emissive: None,
+                        },
+                    );
+                }
+                rendering::components::TweenTarget::Emissive(color) => {
+                    material_colors_changed_messages.write(
+                        rendering::messages::MaterialColorsChangedMessage {
+                            entity,
+                            base_color: None,
+                            emissive: Some(color),
+                        },
+                    );
+                }
+                rendering::components::TweenTarget::Scale(scale) => {
+                    if let Some(transform) = transform.as_mut() {
+                        transform.scale = scale;
+                    }
+                }
+                rendering::components::TweenTarget::Translation(translation) => {
+                    if let Some(transform) = transform.as_mut() {
+                        transform.translation = translation;
+                    }
+                }
             }
+            !finished
+        });
+        if tweens.0.is_empty() {
+            commands
+                .entity(entity)
+                .try_remove::<rendering::components::Tweens>();
         }
     }
 }
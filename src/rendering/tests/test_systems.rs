@@ -1,4 +1,4 @@
-use crate::rendering;
+use crate::{rendering, test_utils};
 use bevy::prelude::*;
 use test_case::test_case;
 
@@ -108,6 +108,11 @@ fn test_update_material_color_system(case: UpdateMaterialColorCase) {
 
     app.update();
 
+    // Recoloring swaps the entity over to a shared variant
+    let material_handle = app
+        .world()
+        .get::<MeshMaterial3d<StandardMaterial>>(entity)
+        .map_or(material_handle, |material| material.0.clone());
     let materials = app.world().resource::<Assets<StandardMaterial>>();
     let material = materials.get(&material_handle).unwrap();
 
@@ -120,6 +125,7 @@ fn setup_update_material_color(has_material: bool) -> (App, Handle<StandardMater
 
     app.init_resource::<Assets<StandardMaterial>>();
     app.init_resource::<Assets<Mesh>>();
+    app.init_resource::<rendering::resources::MaterialCache>();
     app.add_message::<rendering::messages::MaterialColorsChangedMessage>();
     app.add_systems(Update, rendering::systems::update_material_color);
 
@@ -138,3 +144,260 @@ fn setup_update_material_color(has_material: bool) -> (App, Handle<StandardMater
 
     (app, material_handle, entity)
 }
+
+fn spawn_with_material(app: &mut App, material: &Handle<StandardMaterial>) -> Entity {
+    app.world_mut().spawn(MeshMaterial3d(material.clone())).id()
+}
+
+fn material_of(app: &App, entity: Entity) -> Handle<StandardMaterial> {
+    app.world()
+        .get::<MeshMaterial3d<StandardMaterial>>(entity)
+        .unwrap()
+        .0
+        .clone()
+}
+
+fn recolor(app: &mut App, entity: Entity, base_color: Color) {
+    app.world_mut()
+        .write_message(rendering::messages::MaterialColorsChangedMessage {
+            entity,
+            base_color: Some(base_color),
+            emissive: None,
+        });
+}
+
+#[test]
+fn test_update_material_color_leaves_entities_sharing_the_material_alone() {
+    let (mut app, material_handle, entity) = setup_update_material_color(true);
+    let other = spawn_with_material(&mut app, &material_handle);
+
+    recolor(&mut app, entity, Color::linear_rgb(1.0, 0.0, 0.0));
+    app.update();
+
+    assert_ne!(material_of(&app, entity), material_handle);
+    assert_eq!(material_of(&app, other), material_handle);
+    let materials = app.world().resource::<Assets<StandardMaterial>>();
+    assert_eq!(
+        materials.get(&material_handle).unwrap().base_color,
+        StandardMaterial::default().base_color
+    );
+}
+
+#[test]
+fn test_update_material_color_shares_variants_with_the_same_colors() {
+    let (mut app, material_handle, entity) = setup_update_material_color(true);
+    let other = spawn_with_material(&mut app, &material_handle);
+
+    recolor(&mut app, entity, Color::linear_rgb(1.0, 0.0, 0.0));
+    recolor(&mut app, other, Color::linear_rgb(1.0, 0.0, 0.0));
+    app.update();
+
+    assert_eq!(material_of(&app, entity), material_of(&app, other));
+    assert_eq!(app.world().resource::<Assets<StandardMaterial>>().len(), 2);
+}
+
+#[test]
+fn test_update_material_color_returns_to_the_original_material() {
+    let (mut app, material_handle, entity) = setup_update_material_color(true);
+    let original_color = StandardMaterial::default().base_color;
+
+    recolor(&mut app, entity, Color::linear_rgb(1.0, 0.0, 0.0));
+    app.update();
+    recolor(&mut app, entity, original_color);
+    app.update();
+
+    assert_eq!(material_of(&app, entity), material_handle);
+}
+
+fn create_tween_app() -> App {
+    let mut app = App::new();
+    app.init_resource::<Assets<StandardMaterial>>();
+    app.add_message::<rendering::messages::TweenMessage>();
+    app.add_message::<rendering::messages::MaterialColorsChangedMessage>();
+    app.add_systems(
+        Update,
+        (
+            rendering::systems::start_tweens,
+            rendering::systems::advance_tweens,
+        )
+            .chain(),
+    );
+    app.insert_resource(Time::<()>::default());
+    app
+}
+
+fn tween_to(
+    entity: Entity,
+    target: rendering::components::TweenTarget,
+) -> rendering::messages::TweenMessage {
+    rendering::messages::TweenMessage {
+        entity,
+        target,
+        duration: 1.0,
+        easing: EaseFunction::Linear,
+        repeat: rendering::components::TweenRepeat::Once,
+    }
+}
+
+fn advance(app: &mut App, seconds: f32) {
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(std::time::Duration::from_secs_f32(seconds));
+    app.update();
+}
+
+#[test]
+fn test_tween_animates_transform_and_finishes() {
+    let mut app = create_tween_app();
+    let entity = app.world_mut().spawn(Transform::default()).id();
+
+    app.world_mut().write_message(tween_to(
+        entity,
+        rendering::components::TweenTarget::Scale(Vec3::splat(3.0)),
+    ));
+    advance(&mut app, 0.5);
+    assert_eq!(
+        app.world().get::<Transform>(entity).unwrap().scale,
+        Vec3::splat(2.0)
+    );
+
+    advance(&mut app, 0.5);
+    assert_eq!(
+        app.world().get::<Transform>(entity).unwrap().scale,
+        Vec3::splat(3.0)
+    );
+    assert!(
+        app.world()
+            .get::<rendering::components::Tweens>(entity)
+            .is_none()
+    );
+}
+
+#[test]
+fn test_tween_animates_material_colors_through_messages() {
+    let mut app = create_tween_app();
+    let material = app
+        .world_mut()
+        .resource_mut::<Assets<StandardMaterial>>()
+        .add(StandardMaterial {
+            emissive: LinearRgba::BLACK,
+            ..default()
+        });
+    let entity = app.world_mut().spawn(MeshMaterial3d(material)).id();
+
+    app.world_mut().write_message(tween_to(
+        entity,
+        rendering::components::TweenTarget::Emissive(LinearRgba::rgb(1.0, 0.0, 0.0)),
+    ));
+    advance(&mut app, 0.5);
+
+    test_utils::assertions::assert_messages(
+        &app,
+        &[rendering::messages::MaterialColorsChangedMessage {
+            entity,
+            base_color: None,
+            emissive: Some(LinearRgba::rgb(0.5, 0.0, 0.0)),
+        }],
+    );
+}
+
+#[test]
+fn test_tween_animates_base_color_through_the_material_cache() {
+    let mut app = create_tween_app();
+    app.init_resource::<rendering::resources::MaterialCache>();
+    app.add_systems(
+        Update,
+        rendering::systems::update_material_color.after(rendering::systems::advance_tweens),
+    );
+    let material = app
+        .world_mut()
+        .resource_mut::<Assets<StandardMaterial>>()
+        .add(StandardMaterial {
+            base_color: Color::linear_rgb(0.0, 0.0, 1.0),
+            ..default()
+        });
+    let entity = app.world_mut().spawn(MeshMaterial3d(material.clone())).id();
+
+    app.world_mut().write_message(tween_to(
+        entity,
+        rendering::components::TweenTarget::BaseColor(LinearRgba::rgb(1.0, 0.0, 1.0)),
+    ));
+    advance(&mut app, 0.5);
+
+    test_utils::assertions::assert_messages(
+        &app,
+        &[rendering::messages::MaterialColorsChangedMessage {
+            entity,
+            base_color: Some(Color::linear_rgb(0.5, 0.0, 1.0)),
+            emissive: None,
+        }],
+    );
+    // The shared material is left alone, the entity moved to a variant
+    let handle = &app
+        .world()
+        .get::<MeshMaterial3d<StandardMaterial>>(entity)
+        .unwrap()
+        .0;
+    assert_ne!(*handle, material);
+    let materials = app.world().resource::<Assets<StandardMaterial>>();
+    assert_eq!(
+        materials.get(&material).unwrap().base_color,
+        Color::linear_rgb(0.0, 0.0, 1.0)
+    );
+    assert_eq!(
+        materials.get(handle).unwrap().base_color,
+        Color::linear_rgb(0.5, 0.0, 1.0)
+    );
+}
+
+#[test]
+fn test_new_tween_on_the_same_property_blends_from_the_running_one() {
+    let mut app = create_tween_app();
+    let entity = app.world_mut().spawn(Transform::default()).id();
+    app.world_mut().write_message(tween_to(
+        entity,
+        rendering::components::TweenTarget::Translation(Vec3::X * 4.0),
+    ));
+    app.world_mut().write_message(tween_to(
+        entity,
+        rendering::components::TweenTarget::Scale(Vec3::splat(2.0)),
+    ));
+    advance(&mut app, 0.5);
+
+    app.world_mut().write_message(tween_to(
+        entity,
+        rendering::components::TweenTarget::Translation(Vec3::ZERO),
+    ));
+    advance(&mut app, 0.25);
+
+    let tweens = app
+        .world()
+        .get::<rendering::components::Tweens>(entity)
+        .unwrap();
+    assert_eq!(tweens.0.len(), 2);
+    let translation = app.world().get::<Transform>(entity).unwrap().translation;
+    assert!(translation.abs_diff_eq(Vec3::X * 1.5, 1e-5));
+}
+
+#[test_case(rendering::components::TweenRepeat::Once, 1.5, 2.0; "once holds the end value")]
+#[test_case(rendering::components::TweenRepeat::PingPong, 1.5, 1.5; "ping pong runs back")]
+#[test_case(rendering::components::TweenRepeat::PingPong, 2.25, 1.25; "ping pong runs forward again")]
+fn test_tween_value_repeats(
+    repeat: rendering::components::TweenRepeat,
+    elapsed: f32,
+    expected: f32,
+) {
+    let tween = rendering::components::Tween {
+        from: rendering::components::TweenTarget::Scale(Vec3::ONE),
+        to: rendering::components::TweenTarget::Scale(Vec3::splat(2.0)),
+        duration: 1.0,
+        easing: EaseFunction::Linear,
+        repeat,
+        elapsed,
+    };
+
+    assert_eq!(
+        tween.value(),
+        rendering::components::TweenTarget::Scale(Vec3::splat(expected))
+    );
+}
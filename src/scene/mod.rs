@@ -1,6 +1,6 @@
 use bevy::{asset, core_pipeline, mesh, post_process, prelude::*};
 
-use crate::{gameplay, health, physics, states};
+use crate::{camera, gameplay, health, particles, physics, settings, states, theme};
 
 pub fn plugin(app: &mut App) {
     app.add_systems(
@@ -13,34 +13,38 @@ pub fn setup(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    run: Res<gameplay::level::resources::Run>,
+    settings: Res<settings::resources::Settings>,
+    theme: Res<theme::resources::Theme>,
 ) {
     let playfield_half_size = Vec3::new(10.0, 5.0, 20.0);
 
-    let paddle = spawn_paddle(
+    spawn_paddle(
         &mut commands,
         &mut meshes,
         &mut materials,
         playfield_half_size,
+        run.player_health,
+        &theme,
     );
-    let players = vec![paddle];
     spawn_playfield(
         &mut commands,
         &mut meshes,
         &mut materials,
-        &players,
         playfield_half_size,
+        &theme,
     );
-    setup_camera(&mut commands, playfield_half_size);
+    setup_camera(&mut commands, playfield_half_size, &settings);
     setup_lighting(&mut commands);
-    spawn_ball(&mut commands, &mut meshes, &mut materials);
+    spawn_ball(&mut commands, &mut meshes, &mut materials, &theme);
 }
 
 fn spawn_playfield(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
-    players: &[Entity],
     half_size: Vec3,
+    theme: &theme::resources::Theme,
 ) -> gameplay::playfield::resources::Playfield {
     let wall_material = materials.add(Color::srgb(0.0, 0.0, 0.0));
     let clear_wall_material = materials.add(Color::srgba(0.0, 0.0, 0.0, 0.0));
@@ -51,23 +55,20 @@ fn spawn_playfield(
 
     let mut children = vec![];
 
-    let line_default_color = LinearRgba::rgb(0.0, 0.15, 0.0);
-    let line_highlight_color = LinearRgba::rgb(0.0, 0.4, 0.2);
-
+    let line_material = materials.add(StandardMaterial {
+        emissive: theme.wall_line,
+        ..default()
+    });
+    let line_mesh = meshes.add(build_depth_lines_mesh(half_size, line_thickness));
     for i in 0..num_lines {
         let z = -half_size.z + i as f32 * line_spacing;
-        let line_material = materials.add(StandardMaterial {
-            emissive: line_default_color,
-            ..default()
-        });
-        let mesh = meshes.add(build_depth_lines_mesh(half_size, line_thickness));
 
         children.push(
             commands
                 .spawn((
-                    gameplay::playfield::components::DepthLines,
+                    gameplay::playfield::components::DepthLines::default(),
                     Name::new(format!("Depth Line {}", i)),
-                    Mesh3d(mesh),
+                    Mesh3d(line_mesh.clone()),
                     MeshMaterial3d(line_material.clone()),
                     Transform::from_xyz(0.0, 0.0, z),
                 ))
@@ -80,9 +81,9 @@ fn spawn_playfield(
         meshes,
         (wall_material.clone(), clear_wall_material.clone()),
         &mut children,
-        players,
         half_size,
         0.1,
+        theme,
     );
 
     let parent_entity = commands
@@ -98,8 +99,9 @@ fn spawn_playfield(
     }
 
     let playfield = gameplay::playfield::resources::Playfield {
-        wall_line_default_color: line_default_color,
-        wall_line_highlight_color: line_highlight_color,
+        wall_line_default_color: theme.wall_line,
+        wall_line_highlight_color: theme.wall_line_highlight,
+        depth_marker_color: theme.depth_marker,
         brick_size: Vec3::new(4.0, 2.0, 0.25),
     };
     commands.insert_resource(playfield.clone());
@@ -172,9 +174,9 @@ fn spawn_playfield_walls(
     meshes: &mut ResMut<Assets<Mesh>>,
     wall_materials: (Handle<StandardMaterial>, Handle<StandardMaterial>),
     children: &mut Vec<Entity>,
-    players: &[Entity],
     playfield_half_size: Vec3,
     wall_thickness: f32,
+    theme: &theme::resources::Theme,
 ) {
     let (solid_wall_material, clear_wall_material) = wall_materials;
     // axis 0 = X, 1 = Y, 2 = Z
@@ -231,7 +233,10 @@ fn spawn_playfield_walls(
                     Some(gameplay::playfield::components::Goal::Player),
                     Some(health::components::ChangeOnCollision {
                         delta: -1,
-                        affected: health::components::Affects::Others(players.to_vec()),
+                        kind: health::messages::DamageKind::Impact,
+                        affected: health::components::Affects::AllWith(
+                            health::components::TargetMarker::Player,
+                        ),
                     }),
                 ),
                 _ => (None, None),
@@ -252,12 +257,28 @@ fn spawn_playfield_walls(
                     physics::components::BoundingCuboid {
                         half_extents: size * 0.5,
                     },
+                    particles::components::ImpactParticles(particles::messages::ParticleBurst {
+                        count: 4,
+                        speed: 4.0,
+                        spread: 0.6,
+                        lifetime: 0.25,
+                        size: 0.12,
+                        color: theme.wall_sparks,
+                    }),
                 ))
                 .id();
 
             if let Some(goal) = goal {
                 commands.entity(wall_entity).insert(goal);
             }
+            if axis != 2 {
+                // Side walls, the floor and the ceiling show where the ball is along the depth
+                commands.entity(wall_entity).insert(
+                    gameplay::playfield::components::DepthMarkerWall {
+                        normal: -translation.normalize(),
+                    },
+                );
+            }
             if let Some(coll) = change_on_collision {
                 commands.entity(wall_entity).insert(coll);
             }
@@ -266,7 +287,12 @@ fn spawn_playfield_walls(
     }
 }
 
-fn setup_camera(commands: &mut Commands, playfield_half_size: Vec3) {
+fn setup_camera(
+    commands: &mut Commands,
+    playfield_half_size: Vec3,
+    settings: &settings::resources::Settings,
+) {
+    let rig = camera::components::CameraRig::new(settings.camera_mode, playfield_half_size);
     commands.spawn((
         Camera3d::default(),
         Name::new("Camera"),
@@ -276,18 +302,14 @@ fn setup_camera(commands: &mut Commands, playfield_half_size: Vec3) {
         },
         core_pipeline::tonemapping::Tonemapping::TonyMcMapface,
         post_process::bloom::Bloom {
-            intensity: 0.05,
+            intensity: settings.bloom_intensity,
             ..default()
         },
         core_pipeline::tonemapping::DebandDither::Enabled,
-        Projection::Perspective(PerspectiveProjection {
-            fov: std::f32::consts::FRAC_PI_3, // ~60°
-            near: 0.1,
-            far: 200.0,
-            ..default()
-        }),
-        Transform::from_xyz(0.0, 0.0, playfield_half_size.z + 9.0)
-            .looking_at(Vec3::new(0.0, 0.0, -playfield_half_size.z), Vec3::Y),
+        rig.projection(settings.fov_degrees.to_radians()),
+        rig.pose(None, None),
+        camera::components::CameraEffects::default(),
+        rig,
         bevy_inspector_egui::bevy_egui::PrimaryEguiContext,
         DespawnOnExit(states::GameState::Gameplay),
     ));
@@ -298,13 +320,13 @@ fn spawn_paddle(
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
     playfield_half_size: Vec3,
+    carried_health: Option<u8>,
+    theme: &theme::resources::Theme,
 ) -> Entity {
     let bounds = physics::components::BoundingCuboid {
         half_extents: Vec3::new(2.0, 1.0, 0.1),
     };
     let cuboid_dimensions = bounds.half_extents * 2.0;
-    let healthy_color = LinearRgba::new(0.5, 0.7, 1.0, 0.65);
-    let critical_color = LinearRgba::new(1.0, 0.0, 0.0, 0.65);
     commands
         .spawn((
             gameplay::paddle::components::Paddle,
@@ -319,17 +341,54 @@ fn spawn_paddle(
                 cuboid_dimensions.y,
                 cuboid_dimensions.z,
             ))),
-            MeshMaterial3d(materials.add(StandardMaterial {
-                base_color: Color::from(healthy_color),
-                alpha_mode: AlphaMode::Blend,
-                ..default()
-            })),
+            MeshMaterial3d(
+                materials.add(StandardMaterial {
+                    base_color: Color::from(
+                        theme
+                            .paddle_health
+                            .last()
+                            .copied()
+                            .unwrap_or(LinearRgba::WHITE),
+                    ),
+                    alpha_mode: AlphaMode::Blend,
+                    ..default()
+                }),
+            ),
             gameplay::player::components::Player {},
-            health::components::Health { max: 3, current: 3 },
-            health::components::HealthColors {
-                max: healthy_color,
-                min: critical_color,
-            },
+            (
+                health::components::Health {
+                    max: 3,
+                    current: carried_health.unwrap_or(3),
+                },
+                health::components::HealthColors {
+                    stops: theme.paddle_health.clone(),
+                },
+                health::components::LowHealthPulse::new(1, theme.paddle_low_health, 1.5),
+                health::components::InvulnerableOnDamage {
+                    duration: 1.5,
+                    blink_interval: 0.1,
+                },
+                health::components::DeathEffect {
+                    duration: 1.0,
+                    fragments: 16,
+                },
+            ),
+            particles::components::ImpactParticles(particles::messages::ParticleBurst {
+                count: 6,
+                speed: 5.0,
+                spread: 0.7,
+                lifetime: 0.3,
+                size: 0.14,
+                color: theme.paddle_sparks,
+            }),
+            particles::components::SuperCurveParticles(particles::messages::ParticleBurst {
+                count: 20,
+                speed: 9.0,
+                spread: 0.9,
+                lifetime: 0.5,
+                size: 0.18,
+                color: theme.super_curve,
+            }),
             DespawnOnExit(states::GameState::Gameplay),
         ))
         .id()
@@ -339,6 +398,7 @@ fn spawn_ball(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
+    theme: &theme::resources::Theme,
 ) {
     let ball_modifiers = gameplay::ball::components::BallModifiers::starting();
     commands.spawn((
@@ -352,7 +412,8 @@ fn spawn_ball(
         Transform::default(),
         GlobalTransform::default(),
         Mesh3d(meshes.add(Sphere::new(ball_modifiers.base_radius))),
-        MeshMaterial3d(materials.add(Color::srgb_u8(0, 200, 0))),
+        MeshMaterial3d(materials.add(Color::from(theme.ball))),
+        particles::components::ParticleTrail::new(0.5, 0.3, 0.2, theme.ball_trail, 20.0),
         DespawnOnExit(states::GameState::Gameplay),
     ));
 }
@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+#[cfg(test)]
+mod tests;
+
+pub mod resources;
+pub mod systems;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(Startup, systems::load_settings)
+        .add_systems(
+            Update,
+            systems::save_settings.run_if(
+                resource_changed::<resources::Settings>
+                    .and(not(resource_added::<resources::Settings>)),
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                systems::apply_input_settings,
+                systems::apply_window_settings,
+                systems::apply_camera_settings,
+            )
+                .run_if(resource_changed::<resources::Settings>),
+        );
+}
@@ -0,0 +1,290 @@
+use std::ops::RangeInclusive;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub const SETTINGS_FILE: &str = "settings.ron";
+
+pub const MOUSE_SENSITIVITY_RANGE: RangeInclusive<f32> = 0.005..=0.1;
+pub const MOUSE_SENSITIVITY_STEP: f32 = 0.005;
+pub const FOV_DEGREES_RANGE: RangeInclusive<f32> = 40.0..=100.0;
+pub const FOV_DEGREES_STEP: f32 = 5.0;
+pub const BLOOM_INTENSITY_RANGE: RangeInclusive<f32> = 0.0..=0.5;
+pub const BLOOM_INTENSITY_STEP: f32 = 0.01;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PresentModeSetting {
+    VSync,
+    // https://github.com/bevyengine/bevy/issues/3317
+    Immediate,
+}
+
+impl PresentModeSetting {
+    pub fn present_mode(&self) -> bevy::window::PresentMode {
+        match self {
+            PresentModeSetting::VSync => bevy::window::PresentMode::AutoVsync,
+            PresentModeSetting::Immediate => bevy::window::PresentMode::Immediate,
+        }
+    }
+
+    pub fn toggled(&self) -> Self {
+        match self {
+            PresentModeSetting::VSync => PresentModeSetting::Immediate,
+            PresentModeSetting::Immediate => PresentModeSetting::VSync,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CameraModeSetting {
+    Fixed,
+    Lean,   // Leans with the paddle
+    Follow, // Over the shoulder, turning to follow the ball
+    TopDown,
+}
+
+impl CameraModeSetting {
+    pub const ALL: [CameraModeSetting; 4] = [
+        CameraModeSetting::Fixed,
+        CameraModeSetting::Lean,
+        CameraModeSetting::Follow,
+        CameraModeSetting::TopDown,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CameraModeSetting::Fixed => "Fixed",
+            CameraModeSetting::Lean => "Lean",
+            CameraModeSetting::Follow => "Follow",
+            CameraModeSetting::TopDown => "Top Down (Debug)",
+        }
+    }
+
+    pub fn cycled(&self, steps: i8) -> Self {
+        cycled(&Self::ALL, *self, steps)
+    }
+}
+
+/// Built in color palettes, see `theme::resources::Theme`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThemeSetting {
+    Classic,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+}
+
+impl ThemeSetting {
+    pub const ALL: [ThemeSetting; 4] = [
+        ThemeSetting::Classic,
+        ThemeSetting::Deuteranopia,
+        ThemeSetting::Protanopia,
+        ThemeSetting::Tritanopia,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThemeSetting::Classic => "Classic",
+            ThemeSetting::Deuteranopia => "Deuteranopia",
+            ThemeSetting::Protanopia => "Protanopia",
+            ThemeSetting::Tritanopia => "Tritanopia",
+        }
+    }
+
+    pub fn cycled(&self, steps: i8) -> Self {
+        cycled(&Self::ALL, *self, steps)
+    }
+}
+
+/// Moves `steps` options along `all`, wrapping around at either end.
+fn cycled<T: Copy + PartialEq>(all: &[T], current: T, steps: i8) -> T {
+    let count = all.len() as i32;
+    let index = all
+        .iter()
+        .position(|option| *option == current)
+        .unwrap_or(0) as i32;
+    all[(index + i32::from(steps)).rem_euclid(count) as usize]
+}
+
+/// Player preferences, persisted in the config directory and applied live when changed.
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct Settings {
+    pub mouse_sensitivity: f32,
+    pub present_mode: PresentModeSetting,
+    pub fov_degrees: f32,
+    pub bloom_intensity: f32,
+    pub camera_effects: bool, // Shake, kicks and the speed based field of view
+    pub camera_mode: CameraModeSetting,
+    pub theme: ThemeSetting,
+    pub trajectory_preview: bool, // Aim assist showing where the ball is headed
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            mouse_sensitivity: 0.025,
+            present_mode: PresentModeSetting::Immediate,
+            fov_degrees: 60.0,
+            bloom_intensity: 0.05,
+            camera_effects: true,
+            camera_mode: CameraModeSetting::Fixed,
+            theme: ThemeSetting::Classic,
+            trajectory_preview: false,
+        }
+    }
+}
+
+impl Settings {
+    /// Replaces out of range values, e.g. from a hand edited file, with their defaults.
+    pub fn validated(self) -> Self {
+        let defaults = Settings::default();
+        Settings {
+            mouse_sensitivity: validate(
+                "mouse_sensitivity",
+                self.mouse_sensitivity,
+                MOUSE_SENSITIVITY_RANGE,
+                defaults.mouse_sensitivity,
+            ),
+            present_mode: self.present_mode,
+            fov_degrees: validate(
+                "fov_degrees",
+                self.fov_degrees,
+                FOV_DEGREES_RANGE,
+                defaults.fov_degrees,
+            ),
+            bloom_intensity: validate(
+                "bloom_intensity",
+                self.bloom_intensity,
+                BLOOM_INTENSITY_RANGE,
+                defaults.bloom_intensity,
+            ),
+            camera_effects: self.camera_effects,
+            camera_mode: self.camera_mode,
+            theme: self.theme,
+            trajectory_preview: self.trajectory_preview,
+        }
+    }
+}
+
+fn validate(name: &str, value: f32, range: RangeInclusive<f32>, default: f32) -> f32 {
+    if range.contains(&value) {
+        value
+    } else {
+        warn!(
+            "Setting {name} = {value} is outside {}..={}, using {default}",
+            range.start(),
+            range.end()
+        );
+        default
+    }
+}
+
+/// A setting that can be adjusted in steps from the settings screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Setting {
+    MouseSensitivity,
+    PresentMode,
+    FieldOfView,
+    BloomIntensity,
+    CameraEffects,
+    CameraMode,
+    Theme,
+    TrajectoryPreview,
+}
+
+impl Setting {
+    pub const ALL: [Setting; 8] = [
+        Setting::MouseSensitivity,
+        Setting::PresentMode,
+        Setting::FieldOfView,
+        Setting::BloomIntensity,
+        Setting::CameraEffects,
+        Setting::CameraMode,
+        Setting::Theme,
+        Setting::TrajectoryPreview,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Setting::MouseSensitivity => "Mouse Sensitivity",
+            Setting::PresentMode => "Present Mode",
+            Setting::FieldOfView => "Field of View",
+            Setting::BloomIntensity => "Bloom",
+            Setting::CameraEffects => "Camera Effects",
+            Setting::CameraMode => "Camera",
+            Setting::Theme => "Colors",
+            Setting::TrajectoryPreview => "Aim Assist",
+        }
+    }
+
+    pub fn value_text(&self, settings: &Settings) -> String {
+        match self {
+            Setting::MouseSensitivity => format!("{:.3}", settings.mouse_sensitivity),
+            Setting::PresentMode => match settings.present_mode {
+                PresentModeSetting::VSync => "VSync".to_string(),
+                PresentModeSetting::Immediate => "Immediate".to_string(),
+            },
+            Setting::FieldOfView => format!("{:.0}°", settings.fov_degrees),
+            Setting::BloomIntensity => format!("{:.2}", settings.bloom_intensity),
+            Setting::CameraEffects => match settings.camera_effects {
+                true => "On".to_string(),
+                false => "Off".to_string(),
+            },
+            Setting::CameraMode => settings.camera_mode.label().to_string(),
+            Setting::Theme => settings.theme.label().to_string(),
+            Setting::TrajectoryPreview => match settings.trajectory_preview {
+                true => "On".to_string(),
+                false => "Off".to_string(),
+            },
+        }
+    }
+
+    /// Moves the setting `steps` increments, staying within its range.
+    pub fn adjust(&self, settings: &mut Settings, steps: i8) {
+        let step = |value: f32, increment: f32, range: RangeInclusive<f32>| {
+            (value + increment * f32::from(steps)).clamp(*range.start(), *range.end())
+        };
+        match self {
+            Setting::MouseSensitivity => {
+                settings.mouse_sensitivity = step(
+                    settings.mouse_sensitivity,
+                    MOUSE_SENSITIVITY_STEP,
+                    MOUSE_SENSITIVITY_RANGE,
+                );
+            }
+            Setting::PresentMode => {
+                if steps % 2 != 0 {
+                    settings.present_mode = settings.present_mode.toggled();
+                }
+            }
+            Setting::FieldOfView => {
+                settings.fov_degrees =
+                    step(settings.fov_degrees, FOV_DEGREES_STEP, FOV_DEGREES_RANGE);
+            }
+            Setting::BloomIntensity => {
+                settings.bloom_intensity = step(
+                    settings.bloom_intensity,
+                    BLOOM_INTENSITY_STEP,
+                    BLOOM_INTENSITY_RANGE,
+                );
+            }
+            Setting::CameraEffects => {
+                if steps % 2 != 0 {
+                    settings.camera_effects = !settings.camera_effects;
+                }
+            }
+            Setting::CameraMode => {
+                settings.camera_mode = settings.camera_mode.cycled(steps);
+            }
+            Setting::Theme => {
+                settings.theme = settings.theme.cycled(steps);
+            }
+            Setting::TrajectoryPreview => {
+                if steps % 2 != 0 {
+                    settings.trajectory_preview = !settings.trajectory_preview;
+                }
+            }
+        }
+    }
+}
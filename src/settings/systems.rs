@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+
+use crate::input;
+use crate::persistence;
+use crate::settings;
+
+pub fn load_settings(mut commands: Commands) {
+    let settings =
+        persistence::load::<settings::resources::Settings>(settings::resources::SETTINGS_FILE);
+    commands.insert_resource(settings.validated());
+}
+
+pub fn save_settings(settings: Res<settings::resources::Settings>) {
+    persistence::save(settings::resources::SETTINGS_FILE, settings.as_ref());
+}
+
+pub fn apply_input_settings(
+    settings: Res<settings::resources::Settings>,
+    mut config: ResMut<input::resources::PaddleInputConfig>,
+) {
+    config.mouse_sensitivity = settings.mouse_sensitivity;
+}
+
+pub fn apply_window_settings(
+    settings: Res<settings::resources::Settings>,
+    mut windows: Query<&mut Window>,
+) {
+    for mut window in &mut windows {
+        window.present_mode = settings.present_mode.present_mode();
+    }
+}
+
+// The camera only exists during gameplay, `scene::setup` reads the settings when it spawns
+pub fn apply_camera_settings(
+    settings: Res<settings::resources::Settings>,
+    mut cameras: Query<(&mut Projection, &mut bevy::post_process::bloom::Bloom), With<Camera3d>>,
+) {
+    for (mut projection, mut bloom) in &mut cameras {
+        if let Projection::Perspective(perspective) = projection.as_mut() {
+            perspective.fov = settings.fov_degrees.to_radians();
+        }
+        bloom.intensity = settings.bloom_intensity;
+    }
+}
@@ -0,0 +1,223 @@
+use bevy::prelude::*;
+use test_case::test_case;
+
+use crate::input;
+use crate::settings;
+
+#[test]
+fn test_validated_replaces_out_of_range_values_with_defaults() {
+    let loaded = settings::resources::Settings {
+        mouse_sensitivity: 5.0,
+        present_mode: settings::resources::PresentModeSetting::VSync,
+        fov_degrees: 75.0,
+        bloom_intensity: -1.0,
+        camera_effects: false,
+        camera_mode: settings::resources::CameraModeSetting::Follow,
+        theme: settings::resources::ThemeSetting::Protanopia,
+        trajectory_preview: true,
+    };
+
+    let validated = loaded.validated();
+
+    let defaults = settings::resources::Settings::default();
+    assert_eq!(
+        validated,
+        settings::resources::Settings {
+            mouse_sensitivity: defaults.mouse_sensitivity,
+            present_mode: settings::resources::PresentModeSetting::VSync,
+            fov_degrees: 75.0,
+            bloom_intensity: defaults.bloom_intensity,
+            camera_effects: false,
+            camera_mode: settings::resources::CameraModeSetting::Follow,
+            theme: settings::resources::ThemeSetting::Protanopia,
+            trajectory_preview: true,
+        }
+    );
+}
+
+#[test]
+fn test_missing_fields_fall_back_to_defaults() {
+    let loaded: settings::resources::Settings = ron::from_str("(fov_degrees: 90.0)").unwrap();
+
+    assert_eq!(
+        loaded,
+        settings::resources::Settings {
+            fov_degrees: 90.0,
+            ..default()
+        }
+    );
+}
+
+struct AdjustCase {
+    setting: settings::resources::Setting,
+    steps: i8,
+    expected: settings::resources::Settings,
+}
+
+#[test_case(
+    AdjustCase {
+        setting: settings::resources::Setting::MouseSensitivity,
+        steps: 1,
+        expected: settings::resources::Settings {
+            mouse_sensitivity: 0.03,
+            ..default()
+        },
+    }
+; "sensitivity steps up")]
+#[test_case(
+    AdjustCase {
+        setting: settings::resources::Setting::FieldOfView,
+        steps: -1,
+        expected: settings::resources::Settings {
+            fov_degrees: 55.0,
+            ..default()
+        },
+    }
+; "fov steps down")]
+#[test_case(
+    AdjustCase {
+        setting: settings::resources::Setting::BloomIntensity,
+        steps: -10,
+        expected: settings::resources::Settings {
+            bloom_intensity: 0.0,
+            ..default()
+        },
+    }
+; "bloom clamps at its minimum")]
+#[test_case(
+    AdjustCase {
+        setting: settings::resources::Setting::PresentMode,
+        steps: 1,
+        expected: settings::resources::Settings {
+            present_mode: settings::resources::PresentModeSetting::VSync,
+            ..default()
+        },
+    }
+; "present mode toggles")]
+#[test_case(
+    AdjustCase {
+        setting: settings::resources::Setting::CameraEffects,
+        steps: -1,
+        expected: settings::resources::Settings {
+            camera_effects: false,
+            ..default()
+        },
+    }
+; "camera effects toggle off")]
+#[test_case(
+    AdjustCase {
+        setting: settings::resources::Setting::CameraMode,
+        steps: 2,
+        expected: settings::resources::Settings {
+            camera_mode: settings::resources::CameraModeSetting::Follow,
+            ..default()
+        },
+    }
+; "camera mode steps through the modes")]
+#[test_case(
+    AdjustCase {
+        setting: settings::resources::Setting::CameraMode,
+        steps: -1,
+        expected: settings::resources::Settings {
+            camera_mode: settings::resources::CameraModeSetting::TopDown,
+            ..default()
+        },
+    }
+; "camera mode wraps around")]
+#[test_case(
+    AdjustCase {
+        setting: settings::resources::Setting::Theme,
+        steps: 1,
+        expected: settings::resources::Settings {
+            theme: settings::resources::ThemeSetting::Deuteranopia,
+            ..default()
+        },
+    }
+; "theme steps to the next palette")]
+#[test_case(
+    AdjustCase {
+        setting: settings::resources::Setting::TrajectoryPreview,
+        steps: 1,
+        expected: settings::resources::Settings {
+            trajectory_preview: true,
+            ..default()
+        },
+    }
+; "aim assist toggles on")]
+fn test_setting_adjust(case: AdjustCase) {
+    let mut settings = settings::resources::Settings::default();
+
+    case.setting.adjust(&mut settings, case.steps);
+
+    assert!((settings.mouse_sensitivity - case.expected.mouse_sensitivity).abs() < 1e-6);
+    assert_eq!(settings.present_mode, case.expected.present_mode);
+    assert!((settings.fov_degrees - case.expected.fov_degrees).abs() < 1e-4);
+    assert!((settings.bloom_intensity - case.expected.bloom_intensity).abs() < 1e-6);
+    assert_eq!(settings.camera_effects, case.expected.camera_effects);
+    assert_eq!(settings.camera_mode, case.expected.camera_mode);
+    assert_eq!(settings.theme, case.expected.theme);
+    assert_eq!(
+        settings.trajectory_preview,
+        case.expected.trajectory_preview
+    );
+}
+
+fn settings_app(settings: settings::resources::Settings) -> App {
+    let mut app = App::new();
+    app.insert_resource(settings);
+    app.init_resource::<input::resources::PaddleInputConfig>();
+    app.add_systems(
+        Update,
+        (
+            settings::systems::apply_input_settings,
+            settings::systems::apply_window_settings,
+            settings::systems::apply_camera_settings,
+        ),
+    );
+    app
+}
+
+#[test]
+fn test_settings_are_applied_live() {
+    let mut app = settings_app(settings::resources::Settings::default());
+    let window = app.world_mut().spawn(Window::default()).id();
+    let camera = app
+        .world_mut()
+        .spawn((
+            Camera3d::default(),
+            Projection::Perspective(PerspectiveProjection::default()),
+            bevy::post_process::bloom::Bloom::default(),
+        ))
+        .id();
+    app.update();
+
+    *app.world_mut()
+        .resource_mut::<settings::resources::Settings>() = settings::resources::Settings {
+        mouse_sensitivity: 0.05,
+        present_mode: settings::resources::PresentModeSetting::VSync,
+        fov_degrees: 90.0,
+        bloom_intensity: 0.2,
+        camera_effects: false,
+        camera_mode: settings::resources::CameraModeSetting::Fixed,
+        theme: settings::resources::ThemeSetting::Classic,
+        trajectory_preview: true,
+    };
+    app.update();
+
+    let config = app
+        .world()
+        .resource::<input::resources::PaddleInputConfig>();
+    assert_eq!(config.mouse_sensitivity, 0.05);
+    let window = app.world().get::<Window>(window).unwrap();
+    assert_eq!(window.present_mode, bevy::window::PresentMode::AutoVsync);
+    let Projection::Perspective(perspective) = app.world().get::<Projection>(camera).unwrap()
+    else {
+        panic!("camera should keep its perspective projection");
+    };
+    assert!((perspective.fov - 90f32.to_radians()).abs() < 1e-6);
+    let bloom = app
+        .world()
+        .get::<bevy::post_process::bloom::Bloom>(camera)
+        .unwrap();
+    assert_eq!(bloom.intensity, 0.2);
+}
@@ -6,11 +6,26 @@ pub enum GameState {
     #[default]
     Menu,
     Gameplay,
+    LevelComplete,
+    GameOver,
+    // Passed through on the way back into gameplay so the level is torn down and set up again
+    Restarting,
+}
+
+// Only exists during gameplay, physics and player control only run while `Running`
+#[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash, SubStates)]
+#[source(GameState = GameState::Gameplay)]
+pub enum PauseState {
+    #[default]
+    Running,
+    Paused,
 }
 
 #[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash, States)]
 pub enum MenuState {
     Main,
+    Pause,
+    Settings,
     #[default]
     Disabled,
 }
@@ -18,4 +33,5 @@ pub enum MenuState {
 pub fn plugin(app: &mut App) {
     app.init_state::<GameState>();
     app.init_state::<MenuState>();
+    app.add_sub_state::<PauseState>();
 }
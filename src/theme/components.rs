@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use crate::gameplay::playfield;
+use crate::{health, particles};
+
+/// UI node whose background follows `Theme::menu_background`.
+#[derive(Component)]
+pub struct ThemedBackground;
+
+pub type ThemedBrick<'a> = (
+    Entity,
+    &'a health::components::Health,
+    &'a mut health::components::HealthColors,
+    Option<&'a mut health::components::ShieldColor>,
+    Option<&'a health::components::Shield>,
+    &'a mut particles::components::ImpactParticles,
+    &'a mut particles::components::DeathParticles,
+);
+
+pub type ThemedPaddle<'a> = (
+    Entity,
+    &'a health::components::Health,
+    &'a mut health::components::HealthColors,
+    &'a mut health::components::LowHealthPulse,
+    &'a mut particles::components::ImpactParticles,
+    &'a mut particles::components::SuperCurveParticles,
+);
+
+/// Every playfield wall, the goals at either end included.
+pub type ThemedWall = Or<(
+    With<playfield::components::DepthMarkerWall>,
+    With<playfield::components::Goal>,
+)>;
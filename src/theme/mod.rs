@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+use crate::settings;
+
+pub mod components;
+pub mod resources;
+pub mod systems;
+
+#[cfg(test)]
+mod tests;
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<resources::Theme>()
+        .add_systems(
+            Update,
+            systems::apply_theme_setting.run_if(resource_changed::<settings::resources::Settings>),
+        )
+        .add_systems(
+            Update,
+            (
+                systems::recolor_bricks,
+                systems::recolor_paddle,
+                systems::recolor_ball,
+                systems::recolor_playfield,
+                systems::recolor_menus,
+            )
+                // Also runs on the first frame, menus spawned before the saved theme was applied
+                .after(systems::apply_theme_setting)
+                .run_if(resource_changed::<resources::Theme>),
+        );
+}
@@ -0,0 +1,157 @@
+use bevy::{color::palettes::css::CRIMSON, prelude::*};
+
+use crate::settings;
+
+/// Colors every spawn function reads from, replaced live when the theme setting changes.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct Theme {
+    pub brick_health: Vec<LinearRgba>, // From 1 health to full, see `HealthColors`
+    pub brick_shield: LinearRgba,
+    pub brick_sparks: LinearRgba,
+    pub brick_explosion: LinearRgba,
+    pub paddle_health: Vec<LinearRgba>,
+    pub paddle_low_health: LinearRgba,
+    pub paddle_sparks: LinearRgba,
+    pub super_curve: LinearRgba,
+    pub ball: LinearRgba,
+    pub ball_trail: LinearRgba,
+    pub wall_line: LinearRgba,
+    pub wall_line_highlight: LinearRgba,
+    pub wall_sparks: LinearRgba,
+    pub depth_marker: LinearRgba,
+    pub trajectory: LinearRgba,
+    pub menu_background: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::classic()
+    }
+}
+
+impl Theme {
+    pub fn from_setting(setting: settings::resources::ThemeSetting) -> Self {
+        match setting {
+            settings::resources::ThemeSetting::Classic => Theme::classic(),
+            settings::resources::ThemeSetting::Deuteranopia => Theme::deuteranopia(),
+            settings::resources::ThemeSetting::Protanopia => Theme::protanopia(),
+            settings::resources::ThemeSetting::Tritanopia => Theme::tritanopia(),
+        }
+    }
+
+    pub fn classic() -> Self {
+        Theme {
+            brick_health: vec![
+                LinearRgba::rgb(1.0, 0.0, 0.0),
+                LinearRgba::rgb(1.0, 1.0, 0.0),
+                LinearRgba::rgb(0.0, 1.0, 0.0),
+            ],
+            brick_shield: LinearRgba::rgb(0.0, 0.6, 1.0),
+            brick_sparks: LinearRgba::rgb(1.0, 0.9, 0.5),
+            brick_explosion: LinearRgba::rgb(1.0, 0.6, 0.2),
+            paddle_health: vec![
+                LinearRgba::new(1.0, 0.0, 0.0, 0.65),
+                LinearRgba::new(0.5, 0.7, 1.0, 0.65),
+            ],
+            paddle_low_health: LinearRgba::rgb(1.0, 0.1, 0.0),
+            paddle_sparks: LinearRgba::rgb(0.6, 0.8, 1.0),
+            super_curve: LinearRgba::rgb(1.0, 0.4, 1.0),
+            ball: Color::srgb_u8(0, 200, 0).to_linear(),
+            ball_trail: LinearRgba::rgb(0.0, 0.8, 0.0),
+            wall_line: LinearRgba::rgb(0.0, 0.15, 0.0),
+            wall_line_highlight: LinearRgba::rgb(0.0, 0.4, 0.2),
+            wall_sparks: LinearRgba::rgb(0.2, 1.0, 0.4),
+            depth_marker: LinearRgba::new(0.2, 1.0, 0.4, 0.35),
+            trajectory: LinearRgba::rgb(1.0, 1.0, 1.0),
+            menu_background: CRIMSON.into(),
+        }
+    }
+
+    /// Health runs from orange to blue instead of red to green, which read the same without
+    /// green cones.
+    pub fn deuteranopia() -> Self {
+        Theme {
+            brick_health: vec![
+                LinearRgba::rgb(1.0, 0.35, 0.0),
+                LinearRgba::rgb(0.9, 0.9, 0.8),
+                LinearRgba::rgb(0.0, 0.3, 1.0),
+            ],
+            brick_shield: LinearRgba::rgb(0.6, 0.0, 1.0),
+            brick_sparks: LinearRgba::rgb(1.0, 0.9, 0.5),
+            brick_explosion: LinearRgba::rgb(1.0, 0.5, 0.0),
+            paddle_health: vec![
+                LinearRgba::new(1.0, 0.35, 0.0, 0.65),
+                LinearRgba::new(0.3, 0.6, 1.0, 0.65),
+            ],
+            paddle_low_health: LinearRgba::rgb(1.0, 0.5, 0.0),
+            paddle_sparks: LinearRgba::rgb(0.6, 0.8, 1.0),
+            super_curve: LinearRgba::rgb(1.0, 1.0, 1.0),
+            ball: LinearRgba::rgb(0.0, 0.6, 1.0),
+            ball_trail: LinearRgba::rgb(0.0, 0.4, 0.8),
+            wall_line: LinearRgba::rgb(0.0, 0.05, 0.2),
+            wall_line_highlight: LinearRgba::rgb(0.0, 0.2, 0.6),
+            wall_sparks: LinearRgba::rgb(0.3, 0.6, 1.0),
+            depth_marker: LinearRgba::new(0.3, 0.6, 1.0, 0.35),
+            trajectory: LinearRgba::rgb(1.0, 0.8, 0.2),
+            menu_background: Color::srgb(0.0, 0.35, 0.65),
+        }
+    }
+
+    /// Reds look dark without red cones, so low health is bright yellow against blue instead.
+    pub fn protanopia() -> Self {
+        Theme {
+            brick_health: vec![
+                LinearRgba::rgb(1.0, 0.9, 0.0),
+                LinearRgba::rgb(0.6, 0.6, 0.6),
+                LinearRgba::rgb(0.0, 0.25, 1.0),
+            ],
+            brick_shield: LinearRgba::rgb(0.0, 0.8, 1.0),
+            brick_sparks: LinearRgba::rgb(1.0, 1.0, 0.6),
+            brick_explosion: LinearRgba::rgb(1.0, 0.8, 0.0),
+            paddle_health: vec![
+                LinearRgba::new(1.0, 0.9, 0.0, 0.65),
+                LinearRgba::new(0.3, 0.6, 1.0, 0.65),
+            ],
+            paddle_low_health: LinearRgba::rgb(1.0, 0.9, 0.0),
+            paddle_sparks: LinearRgba::rgb(0.6, 0.8, 1.0),
+            super_curve: LinearRgba::rgb(1.0, 1.0, 1.0),
+            ball: LinearRgba::rgb(0.0, 0.7, 1.0),
+            ball_trail: LinearRgba::rgb(0.0, 0.5, 0.8),
+            wall_line: LinearRgba::rgb(0.0, 0.1, 0.15),
+            wall_line_highlight: LinearRgba::rgb(0.0, 0.35, 0.5),
+            wall_sparks: LinearRgba::rgb(0.0, 0.7, 1.0),
+            depth_marker: LinearRgba::new(0.0, 0.7, 1.0, 0.35),
+            trajectory: LinearRgba::rgb(1.0, 1.0, 1.0),
+            menu_background: Color::srgb(0.1, 0.2, 0.5),
+        }
+    }
+
+    /// Blue and yellow run together without blue cones, so health goes from red to teal.
+    pub fn tritanopia() -> Self {
+        Theme {
+            brick_health: vec![
+                LinearRgba::rgb(1.0, 0.0, 0.05),
+                LinearRgba::rgb(1.0, 0.5, 0.6),
+                LinearRgba::rgb(0.0, 0.6, 0.6),
+            ],
+            brick_shield: LinearRgba::rgb(1.0, 1.0, 1.0),
+            brick_sparks: LinearRgba::rgb(1.0, 0.7, 0.8),
+            brick_explosion: LinearRgba::rgb(1.0, 0.2, 0.3),
+            paddle_health: vec![
+                LinearRgba::new(1.0, 0.0, 0.05, 0.65),
+                LinearRgba::new(0.0, 0.7, 0.7, 0.65),
+            ],
+            paddle_low_health: LinearRgba::rgb(1.0, 0.0, 0.1),
+            paddle_sparks: LinearRgba::rgb(0.5, 1.0, 1.0),
+            super_curve: LinearRgba::rgb(1.0, 1.0, 1.0),
+            ball: LinearRgba::rgb(0.0, 0.8, 0.8),
+            ball_trail: LinearRgba::rgb(0.0, 0.6, 0.6),
+            wall_line: LinearRgba::rgb(0.0, 0.12, 0.12),
+            wall_line_highlight: LinearRgba::rgb(0.0, 0.4, 0.4),
+            wall_sparks: LinearRgba::rgb(0.0, 1.0, 1.0),
+            depth_marker: LinearRgba::new(0.0, 1.0, 1.0, 0.35),
+            trajectory: LinearRgba::rgb(1.0, 0.8, 0.9),
+            menu_background: Color::srgb(0.6, 0.05, 0.2),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+
+use crate::gameplay::{ball, brick, paddle, playfield};
+use crate::{particles, rendering, settings, theme};
+
+pub fn apply_theme_setting(
+    settings: Res<settings::resources::Settings>,
+    mut theme: ResMut<theme::resources::Theme>,
+) {
+    theme.set_if_neq(theme::resources::Theme::from_setting(settings.theme));
+}
+
+pub fn recolor_bricks(
+    theme: Res<theme::resources::Theme>,
+    mut query: Query<theme::components::ThemedBrick, With<brick::components::Brick>>,
+    mut material_colors_changed_messages: MessageWriter<
+        rendering::messages::MaterialColorsChangedMessage,
+    >,
+) {
+    for (entity, health, mut colors, shield_color, shield, mut impact, mut death) in &mut query {
+        colors.stops = theme.brick_health.clone();
+        impact.0.color = theme.brick_sparks;
+        death.0.color = theme.brick_explosion;
+        let emissive = shield_color.map(|mut shield_color| {
+            shield_color.0 = theme.brick_shield;
+            let strength = shield.map_or(0.0, |shield| {
+                shield.current as f32 / shield.max.max(1) as f32
+            });
+            (theme.brick_shield * strength).with_alpha(theme.brick_shield.alpha)
+        });
+        material_colors_changed_messages.write(rendering::messages::MaterialColorsChangedMessage {
+            entity,
+            base_color: Some(colors.color_for(health)),
+            emissive,
+        });
+    }
+}
+
+pub fn recolor_paddle(
+    theme: Res<theme::resources::Theme>,
+    mut query: Query<theme::components::ThemedPaddle, With<paddle::components::Paddle>>,
+    mut material_colors_changed_messages: MessageWriter<
+        rendering::messages::MaterialColorsChangedMessage,
+    >,
+) {
+    for (entity, health, mut colors, mut pulse, mut impact, mut super_curve) in &mut query {
+        colors.stops = theme.paddle_health.clone();
+        pulse.color = theme.paddle_low_health;
+        // Restarts a running pulse in the new color
+        pulse.active = false;
+        impact.0.color = theme.paddle_sparks;
+        super_curve.0.color = theme.super_curve;
+        material_colors_changed_messages.write(rendering::messages::MaterialColorsChangedMessage {
+            entity,
+            base_color: Some(colors.color_for(health)),
+            emissive: None,
+        });
+    }
+}
+
+pub fn recolor_ball(
+    theme: Res<theme::resources::Theme>,
+    mut query: Query<
+        (Entity, &mut particles::components::ParticleTrail),
+        With<ball::components::BallModifiers>,
+    >,
+    mut material_colors_changed_messages: MessageWriter<
+        rendering::messages::MaterialColorsChangedMessage,
+    >,
+) {
+    for (entity, mut trail) in &mut query {
+        trail.color = theme.ball_trail;
+        material_colors_changed_messages.write(rendering::messages::MaterialColorsChangedMessage {
+            entity,
+            base_color: Some(Color::from(theme.ball)),
+            emissive: None,
+        });
+    }
+}
+
+pub fn recolor_playfield(
+    theme: Res<theme::resources::Theme>,
+    playfield: Option<ResMut<playfield::resources::Playfield>>,
+    mut walls: Query<&mut particles::components::ImpactParticles, theme::components::ThemedWall>,
+    lines: Query<(Entity, &playfield::components::DepthLines)>,
+    markers: Query<Entity, With<playfield::components::DepthMarker>>,
+    mut material_colors_changed_messages: MessageWriter<
+        rendering::messages::MaterialColorsChangedMessage,
+    >,
+) {
+    if let Some(mut playfield) = playfield {
+        playfield.wall_line_default_color = theme.wall_line;
+        playfield.wall_line_highlight_color = theme.wall_line_highlight;
+        playfield.depth_marker_color = theme.depth_marker;
+    }
+    for mut impact in &mut walls {
+        impact.0.color = theme.wall_sparks;
+    }
+    for (entity, lines) in &lines {
+        let color = if lines.highlighted {
+            theme.wall_line_highlight
+        } else {
+            theme.wall_line
+        };
+        material_colors_changed_messages.write(rendering::messages::MaterialColorsChangedMessage {
+            entity,
+            base_color: None,
+            emissive: Some(color),
+        });
+    }
+    for entity in &markers {
+        material_colors_changed_messages.write(rendering::messages::MaterialColorsChangedMessage {
+            entity,
+            base_color: Some(Color::from(theme.depth_marker)),
+            emissive: None,
+        });
+    }
+}
+
+pub fn recolor_menus(
+    theme: Res<theme::resources::Theme>,
+    mut query: Query<&mut BackgroundColor, With<theme::components::ThemedBackground>>,
+) {
+    for mut background in &mut query {
+        background.0 = theme.menu_background;
+    }
+}
@@ -0,0 +1,274 @@
+use bevy::prelude::*;
+use test_case::test_case;
+
+use crate::gameplay::{brick, paddle, playfield};
+use crate::test_utils;
+use crate::{health, particles, rendering, settings, theme};
+
+#[test_case(settings::resources::ThemeSetting::Classic, theme::resources::Theme::classic() ; "classic")]
+#[test_case(settings::resources::ThemeSetting::Deuteranopia, theme::resources::Theme::deuteranopia() ; "deuteranopia")]
+#[test_case(settings::resources::ThemeSetting::Protanopia, theme::resources::Theme::protanopia() ; "protanopia")]
+#[test_case(settings::resources::ThemeSetting::Tritanopia, theme::resources::Theme::tritanopia() ; "tritanopia")]
+fn test_apply_theme_setting(
+    setting: settings::resources::ThemeSetting,
+    expected: theme::resources::Theme,
+) {
+    let mut app = App::new();
+    app.insert_resource(settings::resources::Settings {
+        theme: setting,
+        ..default()
+    });
+    app.init_resource::<theme::resources::Theme>();
+    app.add_systems(Update, theme::systems::apply_theme_setting);
+    app.update();
+
+    assert_eq!(*app.world().resource::<theme::resources::Theme>(), expected);
+}
+
+#[test]
+fn test_palettes_are_distinct() {
+    let themes = settings::resources::ThemeSetting::ALL.map(theme::resources::Theme::from_setting);
+
+    for (index, theme) in themes.iter().enumerate() {
+        assert!(themes[index + 1..].iter().all(|other| other != theme));
+    }
+}
+
+const BURST: particles::messages::ParticleBurst = particles::messages::ParticleBurst {
+    count: 1,
+    speed: 1.0,
+    spread: 1.0,
+    lifetime: 1.0,
+    size: 1.0,
+    color: LinearRgba::BLACK,
+};
+
+fn create_recolor_app() -> App {
+    let mut app = App::new();
+    app.insert_resource(theme::resources::Theme::protanopia());
+    app.add_message::<rendering::messages::MaterialColorsChangedMessage>();
+    app
+}
+
+#[test]
+fn test_recolor_bricks() {
+    let mut app = create_recolor_app();
+    app.add_systems(Update, theme::systems::recolor_bricks);
+    let brick = app
+        .world_mut()
+        .spawn((
+            brick::components::Brick,
+            health::components::Health { max: 3, current: 1 },
+            health::components::HealthColors {
+                stops: theme::resources::Theme::classic().brick_health,
+            },
+            health::components::Shield::new(2, 3.0, 0.5),
+            health::components::ShieldColor(LinearRgba::BLACK),
+            particles::components::ImpactParticles(BURST),
+            particles::components::DeathParticles(BURST),
+        ))
+        .id();
+    app.update();
+
+    let theme = theme::resources::Theme::protanopia();
+    let world = app.world();
+    assert_eq!(
+        world
+            .get::<health::components::HealthColors>(brick)
+            .unwrap()
+            .stops,
+        theme.brick_health
+    );
+    assert_eq!(
+        world
+            .get::<health::components::ShieldColor>(brick)
+            .unwrap()
+            .0,
+        theme.brick_shield
+    );
+    assert_eq!(
+        world
+            .get::<particles::components::ImpactParticles>(brick)
+            .unwrap()
+            .0
+            .color,
+        theme.brick_sparks
+    );
+    assert_eq!(
+        world
+            .get::<particles::components::DeathParticles>(brick)
+            .unwrap()
+            .0
+            .color,
+        theme.brick_explosion
+    );
+    test_utils::assertions::assert_messages(
+        &app,
+        &[rendering::messages::MaterialColorsChangedMessage {
+            entity: brick,
+            base_color: Some(Color::from(theme.brick_health[0])),
+            emissive: Some(theme.brick_shield),
+        }],
+    );
+}
+
+#[test]
+fn test_recolor_paddle() {
+    let mut app = create_recolor_app();
+    app.add_systems(Update, theme::systems::recolor_paddle);
+    let paddle = app
+        .world_mut()
+        .spawn((
+            paddle::components::Paddle,
+            health::components::Health { max: 3, current: 3 },
+            health::components::HealthColors { stops: vec![] },
+            health::components::LowHealthPulse::new(1, LinearRgba::BLACK, 1.0),
+            particles::components::ImpactParticles(BURST),
+            particles::components::SuperCurveParticles(BURST),
+        ))
+        .id();
+    app.update();
+
+    let theme = theme::resources::Theme::protanopia();
+    assert_eq!(
+        app.world()
+            .get::<health::components::LowHealthPulse>(paddle)
+            .unwrap()
+            .color,
+        theme.paddle_low_health
+    );
+    assert_eq!(
+        app.world()
+            .get::<particles::components::ImpactParticles>(paddle)
+            .unwrap()
+            .0
+            .color,
+        theme.paddle_sparks
+    );
+    test_utils::assertions::assert_messages(
+        &app,
+        &[rendering::messages::MaterialColorsChangedMessage {
+            entity: paddle,
+            base_color: Some(Color::from(theme.paddle_health[1])),
+            emissive: None,
+        }],
+    );
+}
+
+#[test]
+fn test_recolor_playfield() {
+    let mut app = create_recolor_app();
+    app.insert_resource(playfield::resources::Playfield {
+        wall_line_default_color: LinearRgba::BLACK,
+        wall_line_highlight_color: LinearRgba::BLACK,
+        depth_marker_color: LinearRgba::BLACK,
+        brick_size: Vec3::ONE,
+    });
+    app.add_systems(Update, theme::systems::recolor_playfield);
+    let idle = app
+        .world_mut()
+        .spawn(playfield::components::DepthLines { highlighted: false })
+        .id();
+    let highlighted = app
+        .world_mut()
+        .spawn(playfield::components::DepthLines { highlighted: true })
+        .id();
+    let walls = [
+        app.world_mut()
+            .spawn((
+                playfield::components::DepthMarkerWall { normal: Vec3::X },
+                particles::components::ImpactParticles(BURST),
+            ))
+            .id(),
+        app.world_mut()
+            .spawn((
+                playfield::components::Goal::Enemy,
+                particles::components::ImpactParticles(BURST),
+            ))
+            .id(),
+    ];
+    app.update();
+
+    let theme = theme::resources::Theme::protanopia();
+    let playfield = app.world().resource::<playfield::resources::Playfield>();
+    assert_eq!(playfield.wall_line_default_color, theme.wall_line);
+    assert_eq!(
+        playfield.wall_line_highlight_color,
+        theme.wall_line_highlight
+    );
+    assert_eq!(playfield.depth_marker_color, theme.depth_marker);
+    for wall in walls {
+        assert_eq!(
+            app.world()
+                .get::<particles::components::ImpactParticles>(wall)
+                .unwrap()
+                .0
+                .color,
+            theme.wall_sparks
+        );
+    }
+    test_utils::assertions::assert_messages(
+        &app,
+        &[
+            rendering::messages::MaterialColorsChangedMessage {
+                entity: idle,
+                base_color: None,
+                emissive: Some(theme.wall_line),
+            },
+            rendering::messages::MaterialColorsChangedMessage {
+                entity: highlighted,
+                base_color: None,
+                emissive: Some(theme.wall_line_highlight),
+            },
+        ],
+    );
+}
+
+#[test]
+fn test_recolor_menus() {
+    let mut app = create_recolor_app();
+    app.add_systems(Update, theme::systems::recolor_menus);
+    let themed = app
+        .world_mut()
+        .spawn((
+            BackgroundColor(Color::BLACK),
+            theme::components::ThemedBackground,
+        ))
+        .id();
+    let other = app.world_mut().spawn(BackgroundColor(Color::BLACK)).id();
+    app.update();
+
+    assert_eq!(
+        app.world().get::<BackgroundColor>(themed).unwrap().0,
+        theme::resources::Theme::protanopia().menu_background
+    );
+    assert_eq!(
+        app.world().get::<BackgroundColor>(other).unwrap().0,
+        Color::BLACK
+    );
+}
+
+#[test]
+fn test_saved_theme_recolors_menus_on_startup() {
+    let mut app = App::new();
+    app.insert_resource(settings::resources::Settings {
+        theme: settings::resources::ThemeSetting::Protanopia,
+        ..default()
+    });
+    app.add_message::<rendering::messages::MaterialColorsChangedMessage>();
+    app.add_plugins(theme::plugin);
+    // Spawned with the default theme, like the main menu on launch
+    let menu = app
+        .world_mut()
+        .spawn((
+            BackgroundColor(theme::resources::Theme::classic().menu_background),
+            theme::components::ThemedBackground,
+        ))
+        .id();
+    app.update();
+
+    assert_eq!(
+        app.world().get::<BackgroundColor>(menu).unwrap().0,
+        theme::resources::Theme::protanopia().menu_background
+    );
+}